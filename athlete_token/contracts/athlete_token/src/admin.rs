@@ -0,0 +1,45 @@
+use soroban_sdk::{Address, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+
+// Indica se o contrato já foi inicializado (mesmo que o admin tenha renunciado)
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Metadata)
+}
+
+// Lê o administrador atual
+pub fn read_admin(env: &Env) -> Result<Address, Error> {
+    match env.storage().instance().get(&DataKey::Admin) {
+        Some(admin) => Ok(admin),
+        None if is_initialized(env) => Err(Error::AdminRenounced),
+        None => Err(Error::NotInitialized),
+    }
+}
+
+// Grava o administrador
+pub fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+// Lê o administrador proposto que ainda não aceitou
+pub fn read_pending_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingAdmin)
+        .ok_or(Error::NoPendingAdmin)
+}
+
+pub fn write_pending_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::PendingAdmin, admin);
+}
+
+// Remove o administrador e qualquer proposta pendente
+pub fn remove_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::Admin);
+    remove_pending_admin(env);
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::error::Error;
+use crate::storage_types::{AllowanceDataKey, DataKey};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// Lê a autorização de gasto; autorizações expiradas valem zero
+pub fn read_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    match env
+        .storage()
+        .temporary()
+        .get::<DataKey, AllowanceValue>(&key)
+    {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+        Some(allowance) => AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        },
+        None => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+// Grava a autorização de gasto até o ledger de expiração
+pub fn write_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) -> Result<(), Error> {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        return Err(Error::InvalidExpiration);
+    }
+
+    let key = DataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+    env.storage().temporary().set(&key, &allowance);
+
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, live_for, live_for);
+    }
+    Ok(())
+}
+
+// Consome parte da autorização de gasto
+pub fn spend_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        return Err(Error::InsufficientAllowance);
+    }
+    if amount > 0 {
+        let remaining = allowance
+            .amount
+            .checked_sub(amount)
+            .ok_or(Error::Overflow)?;
+        write_allowance(env, from, spender, remaining, allowance.expiration_ledger)?;
+    }
+    Ok(())
+}
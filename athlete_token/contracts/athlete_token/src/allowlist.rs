@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use crate::ttl::extend_persistent;
+
+// Indica se apenas contas aprovadas por KYC podem receber tokens
+pub fn read_allowlist_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowlistEnabled)
+        .unwrap_or(false)
+}
+
+pub fn write_allowlist_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AllowlistEnabled, &enabled);
+}
+
+// Ledger até o qual a aprovação KYC da conta vale, se houver
+pub fn read_approval(env: &Env, account: &Address) -> Option<u32> {
+    let key = DataKey::Allowed(account.clone());
+    let approval = env.storage().persistent().get(&key);
+    if approval.is_some() {
+        extend_persistent(env, &key);
+    }
+    approval
+}
+
+pub fn write_approval(env: &Env, account: &Address, expiration_ledger: u32) {
+    let key = DataKey::Allowed(account.clone());
+    env.storage().persistent().set(&key, &expiration_ledger);
+    extend_persistent(env, &key);
+}
+
+pub fn remove_approval(env: &Env, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Allowed(account.clone()));
+}
+
+// Indica se a conta pode receber tokens no modo atual
+pub fn is_allowed(env: &Env, account: &Address) -> bool {
+    if !read_allowlist_enabled(env) {
+        return true;
+    }
+    read_approval(env, account)
+        .is_some_and(|expiration_ledger| expiration_ledger >= env.ledger().sequence())
+}
+
+// Com o modo allowlist ativo, só contas aprovadas e não expiradas recebem tokens
+pub fn check_allowed(env: &Env, account: &Address) -> Result<(), Error> {
+    if !is_allowed(env, account) {
+        return Err(Error::NotAllowlisted);
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use soroban_sdk::{Address, Env};
+
+use crate::error::Error;
+use crate::holders::{add_holder, remove_holder};
+use crate::snapshots::record_balance_checkpoint;
+use crate::storage_types::DataKey;
+use crate::ttl::extend_persistent;
+
+// Lê o saldo de um endereço
+pub fn read_balance(env: &Env, addr: &Address) -> i128 {
+    let key = DataKey::Balance(addr.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(balance) => {
+            extend_persistent(env, &key);
+            balance
+        }
+        None => 0,
+    }
+}
+
+// Grava o saldo mantendo o índice de titulares e os checkpoints de snapshot:
+// saldos zerados saem do armazenamento e do índice
+fn write_balance(env: &Env, addr: &Address, amount: i128) -> Result<(), Error> {
+    record_balance_checkpoint(env, addr, read_balance(env, addr))?;
+    let key = DataKey::Balance(addr.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        remove_holder(env, addr)?;
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        extend_persistent(env, &key);
+        add_holder(env, addr);
+    }
+    Ok(())
+}
+
+// Credita tokens em um endereço
+pub fn receive_balance(env: &Env, addr: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_balance(env, addr)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_balance(env, addr, balance)
+}
+
+// Debita tokens de um endereço
+pub fn spend_balance(env: &Env, addr: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_balance(env, addr);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    let balance = balance.checked_sub(amount).ok_or(Error::Overflow)?;
+    write_balance(env, addr, balance)
+}
+
+// Mantém viva a entrada de saldo de um titular.
+// Retorna `false` se o endereço não possui saldo registrado.
+pub fn bump_balance(env: &Env, addr: &Address) -> bool {
+    let key = DataKey::Balance(addr.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    extend_persistent(env, &key);
+    true
+}
+
+// Move um saldo legado (chaveado pelo `Address` puro) para `DataKey::Balance`.
+// Retorna o valor migrado (zero se não havia saldo legado). O `mint` antigo
+// aceitava valores negativos: esses saldos são recusados em vez de importados.
+pub fn migrate_legacy_balance(env: &Env, addr: &Address) -> Result<i128, Error> {
+    let storage = env.storage().persistent();
+    match storage.get::<Address, i128>(addr) {
+        Some(legacy) if legacy < 0 => Err(Error::InvalidLegacyBalance),
+        Some(legacy) => {
+            storage.remove(addr);
+            if legacy > 0 {
+                receive_balance(env, addr, legacy)?;
+            }
+            Ok(legacy)
+        }
+        None => Ok(0),
+    }
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::{contracttype, BytesN};
+
+// Motivos aceitos para ações regulatórias sobre saldos
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ReasonCode {
+    CourtOrder = 1,
+    FraudRecovery = 2,
+    LostKeyRecovery = 3,
+    Other = 4,
+}
+
+// Justificativa de clawback/transferência forçada: código e hash do documento
+// que a fundamenta (ordem judicial, laudo, etc.)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnforcementReason {
+    pub code: ReasonCode,
+    pub document_hash: BytesN<32>,
+}
@@ -0,0 +1,46 @@
+use soroban_sdk::contracterror;
+
+// Códigos de erro estáveis expostos pelo contrato
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    InsufficientBalance = 4,
+    InsufficientAllowance = 5,
+    NegativeAmount = 6,
+    InvalidExpiration = 7,
+    InvalidDecimals = 8,
+    Paused = 9,
+    InvalidTtlConfig = 10,
+    ZeroAmount = 11,
+    Overflow = 12,
+    MaxSupplyExceeded = 13,
+    InvalidMaxSupply = 14,
+    InvalidMetadata = 15,
+    AlreadyMigrated = 16,
+    UnknownVersion = 17,
+    AdminRenounced = 18,
+    NoPendingAdmin = 19,
+    InvalidStatusTransition = 20,
+    IssuanceClosed = 21,
+    InitialAllocationExceeded = 22,
+    AccountFrozen = 23,
+    NotAllowlisted = 24,
+    HoldingCapExceeded = 25,
+    InvalidHoldingCap = 26,
+    TransferCountExceeded = 27,
+    TransferVolumeExceeded = 28,
+    InvalidVelocityLimit = 29,
+    PendingNotFound = 30,
+    PendingExpired = 31,
+    PendingNotExpired = 32,
+    InvalidLargeTransferConfig = 33,
+    InvalidBatchSize = 34,
+    InvalidSnapshot = 35,
+    EscrowAccount = 36,
+    InconsistentHolderIndex = 37,
+    InvalidLegacyBalance = 38,
+}
@@ -0,0 +1,293 @@
+use soroban_sdk::{contractevent, Address, BytesN};
+
+use crate::enforcement::ReasonCode;
+use crate::holding_cap::HoldingCap;
+use crate::pending::LargeTransferConfig;
+use crate::roles::Role;
+use crate::status::TokenStatus;
+use crate::velocity::VelocityLimit;
+
+// Eventos compatíveis com SEP-41
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mint {
+    #[topic]
+    pub minter: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+// Transferência para endereço muxed: os dados levam o id do destinatário
+#[contractevent(topics = ["transfer"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferMuxed {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub to_muxed_id: u64,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Approve {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// Eventos administrativos
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub new_admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAccepted {
+    #[topic]
+    pub previous_admin: Address,
+    #[topic]
+    pub new_admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminRenounced {
+    #[topic]
+    pub admin: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgrade {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+#[contractevent(data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Migrate {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[contractevent(data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChanged {
+    #[topic]
+    pub caller: Address,
+    pub from: TokenStatus,
+    pub to: TokenStatus,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HoldingCapChanged {
+    #[topic]
+    pub admin: Address,
+    pub cap: HoldingCap,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapExemptionChanged {
+    #[topic]
+    pub account: Address,
+    pub exempt: bool,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityLimitChanged {
+    #[topic]
+    pub admin: Address,
+    pub limit: Option<VelocityLimit>,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Snapshot {
+    #[topic]
+    pub id: u32,
+    pub ledger: u32,
+}
+
+// Eventos de controle de acesso
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    #[topic]
+    pub account: Address,
+    pub role: Role,
+    pub sender: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    #[topic]
+    pub account: Address,
+    pub role: Role,
+    pub sender: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleAdminChanged {
+    pub role: Role,
+    pub admin_role: Role,
+}
+
+// Eventos de compliance
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Freeze {
+    #[topic]
+    pub account: Address,
+    pub officer: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unfreeze {
+    #[topic]
+    pub account: Address,
+    pub officer: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistModeChanged {
+    pub enabled: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderApproved {
+    #[topic]
+    pub account: Address,
+    pub officer: Address,
+    pub expiration_ledger: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderRevoked {
+    #[topic]
+    pub account: Address,
+    pub officer: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Clawback {
+    #[topic]
+    pub controller: Address,
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+// Trilha de auditoria de clawback/transferência forçada; acompanha os eventos
+// SEP-41 (`clawback`/`transfer`) que registram a movimentação em si
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Enforcement {
+    #[topic]
+    pub controller: Address,
+    #[topic]
+    pub from: Address,
+    pub to: Option<Address>,
+    pub amount: i128,
+    pub reason: ReasonCode,
+    pub document_hash: BytesN<32>,
+}
+
+// Fila de aprovação de transferências grandes
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LargeTransferConfigChanged {
+    #[topic]
+    pub admin: Address,
+    pub config: Option<LargeTransferConfig>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferQueued {
+    #[topic]
+    pub id: u64,
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub to_muxed_id: Option<u64>,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingApproved {
+    #[topic]
+    pub id: u64,
+    pub officer: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRejected {
+    #[topic]
+    pub id: u64,
+    pub officer: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingCancelled {
+    #[topic]
+    pub id: u64,
+    pub from: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingExpired {
+    #[topic]
+    pub id: u64,
+    pub from: Address,
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use crate::ttl::extend_persistent;
+
+// Indica se a conta está congelada por compliance
+pub fn is_frozen(env: &Env, account: &Address) -> bool {
+    let key = DataKey::Frozen(account.clone());
+    let frozen = env.storage().persistent().has(&key);
+    if frozen {
+        extend_persistent(env, &key);
+    }
+    frozen
+}
+
+pub fn write_frozen(env: &Env, account: &Address) {
+    let key = DataKey::Frozen(account.clone());
+    env.storage().persistent().set(&key, &true);
+    extend_persistent(env, &key);
+}
+
+pub fn remove_frozen(env: &Env, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Frozen(account.clone()));
+}
+
+// Contas congeladas não podem enviar nem receber tokens
+pub fn check_not_frozen(env: &Env, account: &Address) -> Result<(), Error> {
+    if is_frozen(env, account) {
+        return Err(Error::AccountFrozen);
+    }
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use crate::ttl::extend_persistent;
+
+// Número de endereços com saldo positivo
+pub fn read_holder_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::HolderCount)
+        .unwrap_or(0)
+}
+
+fn write_holder_count(env: &Env, count: u32) {
+    env.storage().instance().set(&DataKey::HolderCount, &count);
+}
+
+fn read_holder_index(env: &Env, holder: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderIndex(holder.clone()))
+}
+
+fn write_holder_at(env: &Env, index: u32, holder: &Address) {
+    let at_key = DataKey::HolderAt(index);
+    env.storage().persistent().set(&at_key, holder);
+    extend_persistent(env, &at_key);
+    let index_key = DataKey::HolderIndex(holder.clone());
+    env.storage().persistent().set(&index_key, &index);
+    extend_persistent(env, &index_key);
+}
+
+// Inclui o titular no índice; retorna `false` se já estava indexado.
+// A custódia do próprio contrato não entra no índice.
+pub fn add_holder(env: &Env, holder: &Address) -> bool {
+    if *holder == env.current_contract_address() || read_holder_index(env, holder).is_some() {
+        return false;
+    }
+    let count = read_holder_count(env);
+    write_holder_at(env, count, holder);
+    write_holder_count(env, count + 1);
+    true
+}
+
+// Remove o titular do índice movendo o último para a posição liberada
+pub fn remove_holder(env: &Env, holder: &Address) -> Result<(), Error> {
+    let Some(index) = read_holder_index(env, holder) else {
+        return Ok(());
+    };
+    let last = read_holder_count(env)
+        .checked_sub(1)
+        .ok_or(Error::InconsistentHolderIndex)?;
+    if index != last {
+        let moved: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::HolderAt(last))
+            .ok_or(Error::InconsistentHolderIndex)?;
+        write_holder_at(env, index, &moved);
+    }
+    env.storage().persistent().remove(&DataKey::HolderAt(last));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::HolderIndex(holder.clone()));
+    write_holder_count(env, last);
+    Ok(())
+}
+
+// Mantém vivas as entradas do titular no índice
+pub fn bump_holder_index(env: &Env, holder: &Address) {
+    if let Some(index) = read_holder_index(env, holder) {
+        extend_persistent(env, &DataKey::HolderIndex(holder.clone()));
+        extend_persistent(env, &DataKey::HolderAt(index));
+    }
+}
+
+// Página de titulares a partir da posição `start`. Toda posição abaixo do
+// contador precisa estar preenchida; uma lacuna indica índice corrompido.
+pub fn read_holders(env: &Env, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+    let end = start.saturating_add(limit).min(read_holder_count(env));
+    let mut holders = Vec::new(env);
+    for index in start..end {
+        let key = DataKey::HolderAt(index);
+        let holder: Address = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::InconsistentHolderIndex)?;
+        extend_persistent(env, &key);
+        holders.push_back(holder);
+    }
+    Ok(holders)
+}
@@ -0,0 +1,92 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::balance::read_balance;
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use crate::supply::read_max_supply;
+use crate::ttl::extend_persistent;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+// Saldo máximo por carteira (anticoncentração)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HoldingCap {
+    Disabled,
+    // Valor absoluto em unidades do token
+    Absolute(i128),
+    // Fração de `max_supply` em pontos-base (1 = 0,01%)
+    BasisPoints(u32),
+}
+
+pub fn read_holding_cap(env: &Env) -> HoldingCap {
+    env.storage()
+        .instance()
+        .get(&DataKey::HoldingCap)
+        .unwrap_or(HoldingCap::Disabled)
+}
+
+pub fn write_holding_cap(env: &Env, cap: &HoldingCap) -> Result<(), Error> {
+    let valid = match cap {
+        HoldingCap::Disabled => true,
+        HoldingCap::Absolute(amount) => *amount > 0,
+        HoldingCap::BasisPoints(bps) => *bps > 0 && i128::from(*bps) <= BPS_DENOMINATOR,
+    };
+    if !valid {
+        return Err(Error::InvalidHoldingCap);
+    }
+    env.storage().instance().set(&DataKey::HoldingCap, cap);
+    Ok(())
+}
+
+// Indica se a conta está isenta do teto (plataforma, distribuidores)
+pub fn is_cap_exempt(env: &Env, account: &Address) -> bool {
+    let key = DataKey::CapExempt(account.clone());
+    let exempt = env.storage().persistent().has(&key);
+    if exempt {
+        extend_persistent(env, &key);
+    }
+    exempt
+}
+
+pub fn write_cap_exempt(env: &Env, account: &Address, exempt: bool) {
+    let key = DataKey::CapExempt(account.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// Saldo máximo permitido por carteira, se houver teto
+pub fn max_holding(env: &Env) -> Result<Option<i128>, Error> {
+    match read_holding_cap(env) {
+        HoldingCap::Disabled => Ok(None),
+        HoldingCap::Absolute(amount) => Ok(Some(amount)),
+        HoldingCap::BasisPoints(bps) => {
+            let cap = read_max_supply(env)?
+                .checked_mul(i128::from(bps))
+                .ok_or(Error::Overflow)?
+                / BPS_DENOMINATOR;
+            Ok(Some(cap))
+        }
+    }
+}
+
+// Verifica se `to` pode receber `amount` sem ultrapassar o teto
+pub fn check_holding_cap(env: &Env, to: &Address, amount: i128) -> Result<(), Error> {
+    let Some(cap) = max_holding(env)? else {
+        return Ok(());
+    };
+    if is_cap_exempt(env, to) {
+        return Ok(());
+    }
+    let new_balance = read_balance(env, to)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    if new_balance > cap {
+        return Err(Error::HoldingCapExceeded);
+    }
+    Ok(())
+}
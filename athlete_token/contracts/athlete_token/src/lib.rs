@@ -1,38 +1,1150 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Env, Address};
+mod admin;
+mod allowance;
+mod allowlist;
+mod balance;
+mod enforcement;
+mod error;
+mod events;
+mod freeze;
+mod holders;
+mod holding_cap;
+mod metadata;
+mod operations;
+mod pending;
+mod position;
+mod roles;
+mod snapshots;
+mod status;
+mod storage_types;
+mod supply;
+mod ttl;
+mod upgrade;
+mod velocity;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, MuxedAddress, String, Vec};
+
+use crate::admin::{
+    is_initialized, read_admin, read_pending_admin, remove_admin, remove_pending_admin,
+    write_admin, write_pending_admin,
+};
+use crate::allowance::{read_allowance, spend_allowance, write_allowance};
+use crate::allowlist::{
+    check_allowed, is_allowed, read_allowlist_enabled, remove_approval, write_allowlist_enabled,
+    write_approval,
+};
+use crate::balance::{
+    bump_balance, migrate_legacy_balance, read_balance, receive_balance, spend_balance,
+};
+use crate::events::{
+    AdminAccepted, AdminProposed, AdminRenounced, AllowlistModeChanged, Approve, Burn,
+    CapExemptionChanged, Clawback, Enforcement, Freeze, HolderApproved, HolderRevoked,
+    HoldingCapChanged, LargeTransferConfigChanged, Migrate, Mint, PendingApproved,
+    PendingCancelled, PendingExpired, PendingRejected, RoleAdminChanged, RoleGranted, RoleRevoked,
+    Snapshot, StatusChanged, Transfer, TransferMuxed, TransferQueued, Unfreeze, Upgrade,
+    VelocityLimitChanged,
+};
+use crate::freeze::{check_not_frozen, is_frozen, remove_frozen, write_frozen};
+use crate::holders::{add_holder, bump_holder_index, read_holder_count, read_holders};
+use crate::holding_cap::{
+    check_holding_cap, is_cap_exempt, read_holding_cap, write_cap_exempt, write_holding_cap,
+};
+use crate::metadata::{check_metadata, read_metadata, write_metadata};
+use crate::operations::{consume_operation, is_processed};
+use crate::pending::{
+    create_pending, is_expired, read_large_transfer_config, read_locked, read_pending,
+    remove_pending, requires_approval, write_large_transfer_config,
+};
+use crate::roles::{
+    has_role, read_role_admin, remove_role, require_role, require_role_manager, write_role,
+    write_role_admin,
+};
+use crate::snapshots::{balance_at, create_snapshot, read_current_snapshot, total_supply_at};
+use crate::status::{
+    check_can_mint, check_not_paused, read_initial_allocation, read_status, transition,
+    write_initial_allocation, write_status,
+};
+use crate::supply::{
+    decrease_supply, increase_supply, read_max_supply, read_total_supply, record_existing_supply,
+    write_max_supply,
+};
+use crate::ttl::{extend_instance, read_ttl_config, write_ttl_config};
+use crate::upgrade::{read_version, run_migrations, write_version, CURRENT_VERSION};
+use crate::velocity::{read_velocity_limit, record_transfer, write_velocity_limit};
+
+pub use crate::enforcement::{EnforcementReason, ReasonCode};
+pub use crate::error::Error;
+pub use crate::holding_cap::HoldingCap;
+pub use crate::metadata::{AthleteLevel, SportType, TokenMetadata};
+pub use crate::pending::{LargeTransferConfig, PendingTransfer};
+pub use crate::position::HolderPosition;
+pub use crate::roles::Role;
+pub use crate::status::TokenStatus;
+pub use crate::ttl::TtlConfig;
+pub use crate::velocity::VelocityLimit;
+
+fn check_nonnegative_amount(amount: i128) -> Result<(), Error> {
+    if amount < 0 {
+        return Err(Error::NegativeAmount);
+    }
+    Ok(())
+}
+
+fn check_positive_amount(amount: i128) -> Result<(), Error> {
+    check_nonnegative_amount(amount)?;
+    if amount == 0 {
+        return Err(Error::ZeroAmount);
+    }
+    Ok(())
+}
+
+// A custódia de transferências pendentes só se move pela fila de aprovação
+fn check_not_escrow(env: &Env, account: &Address) -> Result<(), Error> {
+    if *account == env.current_contract_address() {
+        return Err(Error::EscrowAccount);
+    }
+    Ok(())
+}
+
+// Entradas de ledger tocadas por destinatário de um lote. Escritas: Balance
+// (ou Pending, quando a transferência vai para a fila), HolderAt, HolderIndex,
+// BalanceCheckpoint e BalanceCheckpointCount. Leituras: Frozen, Allowed,
+// CapExempt e o último BalanceCheckpoint. Atualize ao adicionar armazenamento
+// por titular.
+const BATCH_WRITES_PER_RECIPIENT: u32 = 5;
+const BATCH_READS_PER_RECIPIENT: u32 = 4;
+
+// Entradas fixas do lote, independentes do número de destinatários: instância,
+// código e Operation (mint) ou saldo, janela, Locked, índice e checkpoints do
+// remetente (transferência), além dos checkpoints de oferta
+const BATCH_FIXED_ENTRIES: u32 = 12;
+
+// Limites de entradas por transação da rede. CPU e bytes por destinatário
+// ficam bem abaixo dos limites deles, então a contagem de entradas é o gargalo.
+const TX_WRITE_BUDGET: u32 = 50;
+const TX_FOOTPRINT_BUDGET: u32 = 100;
+
+// Limite de destinatários por lote, derivado do orçamento acima
+const MAX_BATCH_SIZE: u32 = {
+    let by_writes = (TX_WRITE_BUDGET - BATCH_FIXED_ENTRIES) / BATCH_WRITES_PER_RECIPIENT;
+    let by_footprint = (TX_FOOTPRINT_BUDGET - BATCH_FIXED_ENTRIES)
+        / (BATCH_WRITES_PER_RECIPIENT + BATCH_READS_PER_RECIPIENT);
+    if by_writes < by_footprint {
+        by_writes
+    } else {
+        by_footprint
+    }
+};
+
+// Limite de endereços por consulta em lote
+const MAX_QUERY_SIZE: u32 = 100;
+
+fn check_batch_size(len: u32) -> Result<(), Error> {
+    if len == 0 || len > MAX_BATCH_SIZE {
+        return Err(Error::InvalidBatchSize);
+    }
+    Ok(())
+}
 
 // Contract client definition
 #[contract]
 pub struct AthleteToken;
 
+impl AthleteToken {
+    // Emite `amount` para `to` aplicando as regras de emissão e de recebimento
+    fn mint_to(env: &Env, minter: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+        check_can_mint(env, amount)?;
+        check_not_escrow(env, to)?;
+        check_not_frozen(env, to)?;
+        check_allowed(env, to)?;
+        check_holding_cap(env, to, amount)?;
+
+        increase_supply(env, amount)?;
+        receive_balance(env, to, amount)?;
+        Mint {
+            minter: minter.clone(),
+            to: to.clone(),
+            amount,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    // Restrições sobre quem recebe tokens. A custódia só recebe pela fila de
+    // aprovação: tokens enviados direto a ela não estariam ligados a nenhuma pendência.
+    fn check_recipient(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+        check_not_escrow(env, to)?;
+        check_not_frozen(env, to)?;
+        check_allowed(env, to)?;
+        if from != to {
+            check_holding_cap(env, to, amount)?;
+        }
+        Ok(())
+    }
+
+    // Regras comuns a toda movimentação de saldo entre titulares. Transferências
+    // acima do limite vão para custódia no contrato; retorna o id pendente nesse caso.
+    // `to_muxed_id` fica guardado na pendência para o evento da liberação.
+    fn move_balance(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) -> Result<Option<u64>, Error> {
+        check_not_frozen(env, from)?;
+        Self::check_recipient(env, from, to, amount)?;
+        record_transfer(env, from, amount)?;
+        spend_balance(env, from, amount)?;
+        if !requires_approval(env, amount) {
+            receive_balance(env, to, amount)?;
+            return Ok(None);
+        }
+
+        let escrow = env.current_contract_address();
+        receive_balance(env, &escrow, amount)?;
+        let (id, pending) = create_pending(env, from, to, to_muxed_id, amount)?;
+        Transfer {
+            from: from.clone(),
+            to: escrow,
+            amount,
+        }
+        .publish(env);
+        TransferQueued {
+            id,
+            from: pending.from,
+            to: pending.to,
+            to_muxed_id: pending.to_muxed_id,
+            amount,
+            expiration_ledger: pending.expiration_ledger,
+        }
+        .publish(env);
+        Ok(Some(id))
+    }
+
+    // Libera a custódia de uma transferência pendente para `to`
+    fn release_escrow(
+        env: &Env,
+        to: &Address,
+        to_muxed_id: Option<u64>,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let escrow = env.current_contract_address();
+        spend_balance(env, &escrow, amount)?;
+        receive_balance(env, to, amount)?;
+        match to_muxed_id {
+            Some(to_muxed_id) => TransferMuxed {
+                from: escrow,
+                to: to.clone(),
+                to_muxed_id,
+                amount,
+            }
+            .publish(env),
+            None => Transfer {
+                from: escrow,
+                to: to.clone(),
+                amount,
+            }
+            .publish(env),
+        }
+        Ok(())
+    }
+
+    fn change_status(env: &Env, caller: Address, to: TokenStatus) -> Result<(), Error> {
+        extend_instance(env);
+        let from = transition(env, to)?;
+        StatusChanged { caller, from, to }.publish(env);
+        Ok(())
+    }
+}
+
 #[contractimpl]
 impl AthleteToken {
+    // Inicializa o contrato em Draft com administrador, metadados do atleta, oferta
+    // máxima e a alocação inicial emitível antes da ativação (apenas uma vez).
+    // Nenhum papel é concedido implicitamente: use `grant_role`, inclusive para o
+    // próprio administrador, para que a troca de admin não deixe papéis para trás.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        metadata: TokenMetadata,
+        max_supply: i128,
+        initial_allocation: i128,
+    ) -> Result<(), Error> {
+        if is_initialized(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        check_metadata(&metadata)?;
+        if max_supply <= 0 {
+            return Err(Error::InvalidMaxSupply);
+        }
+        check_nonnegative_amount(initial_allocation)?;
+        if initial_allocation > max_supply {
+            return Err(Error::InitialAllocationExceeded);
+        }
+        write_admin(&env, &admin);
+        write_max_supply(&env, max_supply);
+        write_metadata(&env, &metadata);
+        write_status(&env, TokenStatus::Draft);
+        write_initial_allocation(&env, initial_allocation);
+        write_version(&env, CURRENT_VERSION);
+        extend_instance(&env);
+        Ok(())
+    }
+
+    // Mint de tokens (papel Minter). `op_id` identifica a operação no backend
+    // (ex.: hash do `transaction_id` do investimento): reenvios da mesma operação
+    // não creditam de novo e retornam `false`.
+    pub fn mint(
+        env: Env,
+        minter: Address,
+        to: Address,
+        amount: i128,
+        op_id: BytesN<32>,
+    ) -> Result<bool, Error> {
+        check_positive_amount(amount)?;
+        require_role(&env, &minter, Role::Minter)?;
+        extend_instance(&env);
+        if !consume_operation(&env, &op_id) {
+            return Ok(false);
+        }
+
+        Self::mint_to(&env, &minter, &to, amount)?;
+        Ok(true)
+    }
+
+    // Mint para vários destinatários em uma única invocação, tudo ou nada
+    // (papel Minter). Idempotente por `op_id`, como `mint`.
+    pub fn batch_mint(
+        env: Env,
+        minter: Address,
+        recipients: Vec<(Address, i128)>,
+        op_id: BytesN<32>,
+    ) -> Result<bool, Error> {
+        check_batch_size(recipients.len())?;
+        require_role(&env, &minter, Role::Minter)?;
+        extend_instance(&env);
+        if !consume_operation(&env, &op_id) {
+            return Ok(false);
+        }
+
+        for (to, amount) in recipients.iter() {
+            check_positive_amount(amount)?;
+            Self::mint_to(&env, &minter, &to, amount)?;
+        }
+        Ok(true)
+    }
+
+    // Migra saldos legados (chaveados pelo `Address` puro) para `DataKey::Balance`.
+    // Endereços já migrados são ignorados; retorna quantos saldos foram movidos.
+    pub fn migrate_balances(env: Env, holders: Vec<Address>) -> Result<u32, Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        let mut migrated = 0;
+        for holder in holders.iter() {
+            let moved = migrate_legacy_balance(&env, &holder)?;
+            if moved != 0 {
+                record_existing_supply(&env, moved)?;
+                migrated += 1;
+            }
+        }
+        Ok(migrated)
+    }
+
+    // Inclui no índice de titulares endereços que já tinham saldo antes da v2
+    // (somente o administrador). Retorna quantos foram adicionados.
+    pub fn index_holders(env: Env, holders: Vec<Address>) -> Result<u32, Error> {
+        if holders.len() > MAX_QUERY_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        let mut indexed = 0;
+        for holder in holders.iter() {
+            if read_balance(&env, &holder) > 0 && add_holder(&env, &holder) {
+                indexed += 1;
+            }
+        }
+        Ok(indexed)
+    }
+
+    // Mantém vivo o saldo de um titular (permissionless, para keepers).
+    // Retorna `false` se o endereço não possui saldo registrado.
+    pub fn bump_holder(env: Env, holder: Address) -> bool {
+        extend_instance(&env);
+        bump_holder_index(&env, &holder);
+        bump_balance(&env, &holder)
+    }
+
+    // Atualiza os limites de TTL usados nas extensões (somente o administrador)
+    pub fn set_ttl_config(env: Env, config: TtlConfig) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        write_ttl_config(&env, &config)?;
+        extend_instance(&env);
+        Ok(())
+    }
+
+    // Configuração de TTL em vigor
+    pub fn ttl_config(env: Env) -> TtlConfig {
+        read_ttl_config(&env)
+    }
+
+    // Propõe um novo administrador; a troca só vale após `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        write_pending_admin(&env, &new_admin);
+        AdminProposed { admin, new_admin }.publish(&env);
+        Ok(())
+    }
+
+    // Aceita a administração proposta (assinado pelo novo administrador)
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let previous_admin = read_admin(&env)?;
+        let new_admin = read_pending_admin(&env)?;
+        new_admin.require_auth();
+        extend_instance(&env);
+
+        write_admin(&env, &new_admin);
+        remove_pending_admin(&env);
+        AdminAccepted {
+            previous_admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Renuncia à administração de forma irreversível
+    pub fn renounce_admin(env: Env) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        remove_admin(&env);
+        AdminRenounced { admin }.publish(&env);
+        Ok(())
+    }
+
+    // Administrador atual
+    pub fn admin(env: Env) -> Result<Address, Error> {
+        read_admin(&env)
+    }
+
+    // Abre a emissão primária: Draft → Active (somente o administrador)
+    pub fn activate(env: Env) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        Self::change_status(&env, admin, TokenStatus::Active)
+    }
+
+    // Pausa movimentações: Active → Paused (papel Pauser)
+    pub fn pause(env: Env, pauser: Address) -> Result<(), Error> {
+        require_role(&env, &pauser, Role::Pauser)?;
+        Self::change_status(&env, pauser, TokenStatus::Paused)
+    }
+
+    // Retoma movimentações: Paused → Active (papel Pauser)
+    pub fn unpause(env: Env, pauser: Address) -> Result<(), Error> {
+        require_role(&env, &pauser, Role::Pauser)?;
+        Self::change_status(&env, pauser, TokenStatus::Active)
+    }
+
+    // Encerra a emissão primária: Active → Completed (somente o administrador)
+    pub fn complete(env: Env) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        Self::change_status(&env, admin, TokenStatus::Completed)
+    }
+
+    // Status atual do ciclo de vida
+    pub fn status(env: Env) -> Result<TokenStatus, Error> {
+        read_status(&env)
+    }
+
+    // Alocação inicial emitível em Draft
+    pub fn initial_allocation(env: Env) -> i128 {
+        read_initial_allocation(&env)
+    }
+
+    // Congela uma conta: ela não pode enviar nem receber tokens (papel Compliance)
+    pub fn freeze(env: Env, officer: Address, account: Address) -> Result<(), Error> {
+        require_role(&env, &officer, Role::Compliance)?;
+        extend_instance(&env);
+
+        write_frozen(&env, &account);
+        Freeze { account, officer }.publish(&env);
+        Ok(())
+    }
+
+    // Descongela uma conta (papel Compliance)
+    pub fn unfreeze(env: Env, officer: Address, account: Address) -> Result<(), Error> {
+        require_role(&env, &officer, Role::Compliance)?;
+        extend_instance(&env);
+
+        remove_frozen(&env, &account);
+        Unfreeze { account, officer }.publish(&env);
+        Ok(())
+    }
+
+    // Liga/desliga o modo allowlist: só contas aprovadas por KYC recebem tokens
+    // (somente o administrador)
+    pub fn set_allowlist_mode(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        write_allowlist_enabled(&env, enabled);
+        AllowlistModeChanged { enabled }.publish(&env);
+        Ok(())
+    }
+
+    // Aprova contas verificadas até `expiration_ledger` (papel KycOfficer)
+    pub fn approve_holders(
+        env: Env,
+        officer: Address,
+        accounts: Vec<Address>,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        require_role(&env, &officer, Role::KycOfficer)?;
+        extend_instance(&env);
+        if expiration_ledger < env.ledger().sequence() {
+            return Err(Error::InvalidExpiration);
+        }
+
+        for account in accounts.iter() {
+            write_approval(&env, &account, expiration_ledger);
+            HolderApproved {
+                account,
+                officer: officer.clone(),
+                expiration_ledger,
+            }
+            .publish(&env);
+        }
+        Ok(())
+    }
+
+    // Revoga a aprovação KYC de contas (papel KycOfficer)
+    pub fn revoke_holders(env: Env, officer: Address, accounts: Vec<Address>) -> Result<(), Error> {
+        require_role(&env, &officer, Role::KycOfficer)?;
+        extend_instance(&env);
+
+        for account in accounts.iter() {
+            remove_approval(&env, &account);
+            HolderRevoked {
+                account,
+                officer: officer.clone(),
+            }
+            .publish(&env);
+        }
+        Ok(())
+    }
+
+    // Indica se a conta pode receber tokens (sempre verdadeiro fora do modo allowlist)
+    pub fn is_allowed(env: Env, account: Address) -> bool {
+        is_allowed(&env, &account)
+    }
+
+    // Indica se o modo allowlist está ativo
+    pub fn allowlist_enabled(env: Env) -> bool {
+        read_allowlist_enabled(&env)
+    }
+
+    // Define o saldo máximo por carteira (somente o administrador)
+    pub fn set_holding_cap(env: Env, cap: HoldingCap) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        write_holding_cap(&env, &cap)?;
+        HoldingCapChanged { admin, cap }.publish(&env);
+        Ok(())
+    }
+
+    // Isenta (ou deixa de isentar) uma conta do teto por carteira (somente o administrador)
+    pub fn set_cap_exempt(env: Env, account: Address, exempt: bool) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        write_cap_exempt(&env, &account, exempt);
+        CapExemptionChanged { account, exempt }.publish(&env);
+        Ok(())
+    }
+
+    // Teto por carteira em vigor
+    pub fn holding_cap(env: Env) -> HoldingCap {
+        read_holding_cap(&env)
+    }
+
+    // Indica se a conta está isenta do teto por carteira
+    pub fn is_cap_exempt(env: Env, account: Address) -> bool {
+        is_cap_exempt(&env, &account)
+    }
+
+    // Define (ou remove, com `None`) os limites de velocidade por remetente
+    // (somente o administrador)
+    pub fn set_velocity_limit(env: Env, limit: Option<VelocityLimit>) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        write_velocity_limit(&env, &limit)?;
+        VelocityLimitChanged { admin, limit }.publish(&env);
+        Ok(())
+    }
+
+    // Limites de velocidade em vigor
+    pub fn velocity_limit(env: Env) -> Option<VelocityLimit> {
+        read_velocity_limit(&env)
+    }
+
+    // Define (ou remove, com `None`) o limite acima do qual transferências exigem
+    // aprovação de compliance (somente o administrador)
+    pub fn set_large_transfer_config(
+        env: Env,
+        config: Option<LargeTransferConfig>,
+    ) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        write_large_transfer_config(&env, &config)?;
+        LargeTransferConfigChanged { admin, config }.publish(&env);
+        Ok(())
+    }
+
+    // Configuração da fila de transferências grandes
+    pub fn large_transfer_config(env: Env) -> Option<LargeTransferConfig> {
+        read_large_transfer_config(&env)
+    }
+
+    // Transferência pendente pelo id
+    pub fn pending_transfer(env: Env, id: u64) -> Result<PendingTransfer, Error> {
+        read_pending(&env, id)
+    }
+
+    // Aprova uma transferência pendente, liberando a custódia ao destinatário
+    // (papel Compliance)
+    pub fn approve_pending(env: Env, officer: Address, id: u64) -> Result<(), Error> {
+        require_role(&env, &officer, Role::Compliance)?;
+        extend_instance(&env);
+        check_not_paused(&env)?;
+
+        let pending = read_pending(&env, id)?;
+        if is_expired(&env, &pending) {
+            return Err(Error::PendingExpired);
+        }
+        Self::check_recipient(&env, &pending.from, &pending.to, pending.amount)?;
+        remove_pending(&env, id, &pending)?;
+        Self::release_escrow(&env, &pending.to, pending.to_muxed_id, pending.amount)?;
+        PendingApproved { id, officer }.publish(&env);
+        Ok(())
+    }
+
+    // Rejeita uma transferência pendente, devolvendo os tokens ao remetente
+    // (papel Compliance)
+    pub fn reject_pending(env: Env, officer: Address, id: u64) -> Result<(), Error> {
+        require_role(&env, &officer, Role::Compliance)?;
+        extend_instance(&env);
+
+        let pending = read_pending(&env, id)?;
+        remove_pending(&env, id, &pending)?;
+        Self::release_escrow(&env, &pending.from, None, pending.amount)?;
+        PendingRejected { id, officer }.publish(&env);
+        Ok(())
+    }
+
+    // Cancela a própria transferência pendente (assinada pelo remetente)
+    pub fn cancel_pending(env: Env, id: u64) -> Result<(), Error> {
+        let pending = read_pending(&env, id)?;
+        pending.from.require_auth();
+        extend_instance(&env);
+
+        remove_pending(&env, id, &pending)?;
+        Self::release_escrow(&env, &pending.from, None, pending.amount)?;
+        PendingCancelled {
+            id,
+            from: pending.from,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Devolve ao remetente uma transferência pendente já expirada (permissionless)
+    pub fn expire_pending(env: Env, id: u64) -> Result<(), Error> {
+        extend_instance(&env);
+
+        let pending = read_pending(&env, id)?;
+        if !is_expired(&env, &pending) {
+            return Err(Error::PendingNotExpired);
+        }
+        remove_pending(&env, id, &pending)?;
+        Self::release_escrow(&env, &pending.from, None, pending.amount)?;
+        PendingExpired {
+            id,
+            from: pending.from,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Retira tokens de `from` sem a assinatura do titular, queimando-os (papel Controller).
+    // Ignora pausa e congelamento: é o caminho de recuperação para esses casos.
+    // Como em `mint`, reenvios do mesmo `op_id` não têm efeito e retornam `false`.
+    pub fn clawback(
+        env: Env,
+        controller: Address,
+        from: Address,
+        amount: i128,
+        reason: EnforcementReason,
+        op_id: BytesN<32>,
+    ) -> Result<bool, Error> {
+        check_positive_amount(amount)?;
+        require_role(&env, &controller, Role::Controller)?;
+        extend_instance(&env);
+        if !consume_operation(&env, &op_id) {
+            return Ok(false);
+        }
+        check_not_escrow(&env, &from)?;
+
+        spend_balance(&env, &from, amount)?;
+        decrease_supply(&env, amount)?;
+        Clawback {
+            controller: controller.clone(),
+            from: from.clone(),
+            amount,
+        }
+        .publish(&env);
+        Enforcement {
+            controller,
+            from,
+            to: None,
+            amount,
+            reason: reason.code,
+            document_hash: reason.document_hash,
+        }
+        .publish(&env);
+        Ok(true)
+    }
+
+    // Move tokens de `from` para `to` sem a assinatura do titular (papel Controller).
+    // Ignora pausa e congelamento de `from`, mas `to` precisa poder receber.
+    // Como em `mint`, reenvios do mesmo `op_id` não têm efeito e retornam `false`.
+    pub fn forced_transfer(
+        env: Env,
+        controller: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+        reason: EnforcementReason,
+        op_id: BytesN<32>,
+    ) -> Result<bool, Error> {
+        check_positive_amount(amount)?;
+        require_role(&env, &controller, Role::Controller)?;
+        extend_instance(&env);
+        if !consume_operation(&env, &op_id) {
+            return Ok(false);
+        }
+        check_not_escrow(&env, &from)?;
+        Self::check_recipient(&env, &from, &to, amount)?;
+
+        spend_balance(&env, &from, amount)?;
+        receive_balance(&env, &to, amount)?;
+        Transfer {
+            from: from.clone(),
+            to: to.clone(),
+            amount,
+        }
+        .publish(&env);
+        Enforcement {
+            controller,
+            from,
+            to: Some(to),
+            amount,
+            reason: reason.code,
+            document_hash: reason.document_hash,
+        }
+        .publish(&env);
+        Ok(true)
+    }
+
+    // Indica se a operação `op_id` já foi aplicada (para conciliação após timeout)
+    pub fn is_processed(env: Env, op_id: BytesN<32>) -> bool {
+        is_processed(&env, &op_id)
+    }
+
+    // Indica se a conta está congelada
+    pub fn is_frozen(env: Env, account: Address) -> bool {
+        is_frozen(&env, &account)
+    }
+
+    // Concede `role` a `account` (administrador do contrato ou do papel)
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        account: Address,
+        role: Role,
+    ) -> Result<(), Error> {
+        require_role_manager(&env, &caller, role)?;
+        extend_instance(&env);
+
+        write_role(&env, &account, role);
+        RoleGranted {
+            account,
+            role,
+            sender: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Revoga `role` de `account` (administrador do contrato ou do papel)
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        account: Address,
+        role: Role,
+    ) -> Result<(), Error> {
+        require_role_manager(&env, &caller, role)?;
+        extend_instance(&env);
+
+        remove_role(&env, &account, role);
+        RoleRevoked {
+            account,
+            role,
+            sender: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Abre mão de um papel próprio
+    pub fn renounce_role(env: Env, account: Address, role: Role) -> Result<(), Error> {
+        require_role(&env, &account, role)?;
+        extend_instance(&env);
+
+        remove_role(&env, &account, role);
+        RoleRevoked {
+            account: account.clone(),
+            role,
+            sender: account,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Define qual papel administra `role` (somente o administrador do contrato)
+    pub fn set_role_admin(env: Env, role: Role, admin_role: Role) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        write_role_admin(&env, role, admin_role);
+        RoleAdminChanged { role, admin_role }.publish(&env);
+        Ok(())
+    }
+
+    // Indica se `account` possui `role`
+    pub fn has_role(env: Env, account: Address, role: Role) -> bool {
+        has_role(&env, &account, role)
+    }
+
+    // Papel que administra `role`, se houver
+    pub fn role_admin(env: Env, role: Role) -> Option<Role> {
+        read_role_admin(&env, role)
+    }
+
+    // Substitui o WASM do contrato preservando o armazenamento (somente o administrador).
+    // Após o upgrade, chame `migrate` para aplicar as transformações da nova versão.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        Upgrade {
+            admin,
+            wasm_hash: new_wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    // Aplica as migrações pendentes até a versão do código atual (somente o administrador)
+    pub fn migrate(env: Env) -> Result<u32, Error> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        extend_instance(&env);
+
+        let from_version = run_migrations(&env)?;
+        Migrate {
+            from_version,
+            to_version: CURRENT_VERSION,
+        }
+        .publish(&env);
+        Ok(CURRENT_VERSION)
+    }
+
+    // Versão do esquema de armazenamento
+    pub fn version(env: Env) -> u32 {
+        read_version(&env)
+    }
+
+    // Retorna quanto `spender` ainda pode gastar de `from`
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        extend_instance(&env);
+        read_allowance(&env, &from, &spender).amount
+    }
+
+    // Autoriza `spender` a gastar até `amount` de `from` até `expiration_ledger`
+    pub fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        extend_instance(&env);
+        check_nonnegative_amount(amount)?;
+
+        write_allowance(&env, &from, &spender, amount, expiration_ledger)?;
+        Approve {
+            from,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
     // Retorna saldo
-    pub fn balance(env: Env, owner: Address) -> i128 {
-        env.storage()
-            .persistent()
-            .get::<Address, i128>(&owner)
-            .unwrap_or(0)
+    pub fn balance(env: Env, id: Address) -> i128 {
+        extend_instance(&env);
+        read_balance(&env, &id)
+    }
+
+    // Saldos de vários endereços em uma única consulta
+    pub fn balances(env: Env, ids: Vec<Address>) -> Result<Vec<i128>, Error> {
+        if ids.len() > MAX_QUERY_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        extend_instance(&env);
+
+        let mut balances = Vec::new(&env);
+        for id in ids.iter() {
+            balances.push_back(read_balance(&env, &id));
+        }
+        Ok(balances)
+    }
+
+    // Número de endereços com saldo positivo
+    pub fn holder_count(env: Env) -> u32 {
+        extend_instance(&env);
+        read_holder_count(&env)
+    }
+
+    // Titulares paginados: até `limit` endereços a partir da posição `start`.
+    // A ordem muda quando um titular zera o saldo (o último ocupa sua posição).
+    pub fn holders(env: Env, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+        if limit > MAX_QUERY_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        extend_instance(&env);
+        read_holders(&env, start, limit)
+    }
+
+    // Posição consolidada de um titular para telas de portfólio
+    pub fn position(env: Env, id: Address) -> HolderPosition {
+        extend_instance(&env);
+        HolderPosition {
+            balance: read_balance(&env, &id),
+            locked: read_locked(&env, &id),
+            claimable_revenue: None,
+            frozen: is_frozen(&env, &id),
+            allowed: is_allowed(&env, &id),
+        }
+    }
+
+    // Transferência de tokens (autorizada pelo titular)
+    pub fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) -> Result<(), Error> {
+        from.require_auth();
+        extend_instance(&env);
+        check_positive_amount(amount)?;
+        check_not_paused(&env)?;
+
+        if Self::move_balance(&env, &from, &to.address(), to.id(), amount)?.is_some() {
+            return Ok(());
+        }
+        match to.id() {
+            Some(to_muxed_id) => TransferMuxed {
+                from,
+                to: to.address(),
+                to_muxed_id,
+                amount,
+            }
+            .publish(&env),
+            None => Transfer {
+                from,
+                to: to.address(),
+                amount,
+            }
+            .publish(&env),
+        }
+        Ok(())
     }
 
-    // Mint de tokens
-    pub fn mint(env: Env, owner: Address, amount: i128) {
-        let storage = env.storage().persistent();
-        let current: i128 = storage.get::<Address, i128>(&owner).unwrap_or(0);
-        storage.set(&owner, &(current + amount));
+    // Transfere de `from` para vários destinatários em uma única invocação,
+    // tudo ou nada (autorizada pelo titular)
+    pub fn batch_transfer(
+        env: Env,
+        from: Address,
+        recipients: Vec<(Address, i128)>,
+    ) -> Result<(), Error> {
+        check_batch_size(recipients.len())?;
+        from.require_auth();
+        extend_instance(&env);
+        check_not_paused(&env)?;
+
+        for (to, amount) in recipients.iter() {
+            check_positive_amount(amount)?;
+            if Self::move_balance(&env, &from, &to, None, amount)?.is_none() {
+                Transfer {
+                    from: from.clone(),
+                    to,
+                    amount,
+                }
+                .publish(&env);
+            }
+        }
+        Ok(())
+    }
+
+    // Transferência de tokens por um gastador autorizado
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        spender.require_auth();
+        extend_instance(&env);
+        check_positive_amount(amount)?;
+        check_not_paused(&env)?;
+
+        check_not_frozen(&env, &spender)?;
+        spend_allowance(&env, &from, &spender, amount)?;
+        if Self::move_balance(&env, &from, &to, None, amount)?.is_none() {
+            Transfer { from, to, amount }.publish(&env);
+        }
+        Ok(())
+    }
+
+    // Queima tokens do próprio titular
+    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), Error> {
+        from.require_auth();
+        extend_instance(&env);
+        check_positive_amount(amount)?;
+        check_not_paused(&env)?;
+        check_not_frozen(&env, &from)?;
+
+        spend_balance(&env, &from, amount)?;
+        decrease_supply(&env, amount)?;
+        Burn { from, amount }.publish(&env);
+        Ok(())
+    }
+
+    // Queima tokens de `from` por um gastador autorizado
+    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) -> Result<(), Error> {
+        spender.require_auth();
+        extend_instance(&env);
+        check_positive_amount(amount)?;
+        check_not_paused(&env)?;
+        check_not_frozen(&env, &spender)?;
+        check_not_frozen(&env, &from)?;
+
+        spend_allowance(&env, &from, &spender, amount)?;
+        spend_balance(&env, &from, amount)?;
+        decrease_supply(&env, amount)?;
+        Burn { from, amount }.publish(&env);
+        Ok(())
     }
 
-    // Transferência de tokens
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> bool {
-        let storage = env.storage().persistent();
-        let from_balance: i128 = storage.get::<Address, i128>(&from).unwrap_or(0);
-        if from_balance < amount {
-            return false;
+    // Oferta total em circulação
+    pub fn total_supply(env: Env) -> i128 {
+        read_total_supply(&env)
+    }
+
+    // Registra um snapshot (data de corte) e retorna seu id (papel RevenueManager)
+    pub fn snapshot(env: Env, manager: Address) -> Result<u32, Error> {
+        require_role(&env, &manager, Role::RevenueManager)?;
+        extend_instance(&env);
+
+        let id = create_snapshot(&env);
+        Snapshot {
+            id,
+            ledger: env.ledger().sequence(),
         }
-        let to_balance: i128 = storage.get::<Address, i128>(&to).unwrap_or(0);
-        storage.set(&from, &(from_balance - amount));
-        storage.set(&to, &(to_balance + amount));
-        true
+        .publish(&env);
+        Ok(id)
+    }
+
+    // Id do snapshot mais recente (0 se nenhum)
+    pub fn current_snapshot(env: Env) -> u32 {
+        read_current_snapshot(&env)
+    }
+
+    // Saldo de `id` no momento do snapshot `snapshot_id`
+    pub fn balance_at(env: Env, id: Address, snapshot_id: u32) -> Result<i128, Error> {
+        extend_instance(&env);
+        let current = read_balance(&env, &id);
+        balance_at(&env, &id, snapshot_id, current)
+    }
+
+    // Oferta total no momento do snapshot `snapshot_id`
+    pub fn total_supply_at(env: Env, snapshot_id: u32) -> Result<i128, Error> {
+        extend_instance(&env);
+        total_supply_at(&env, snapshot_id, read_total_supply(&env))
+    }
+
+    // Oferta máxima prometida na campanha
+    pub fn max_supply(env: Env) -> Result<i128, Error> {
+        read_max_supply(&env)
+    }
+
+    // Metadados do atleta e do token
+    pub fn metadata(env: Env) -> Result<TokenMetadata, Error> {
+        read_metadata(&env)
+    }
+
+    // Casas decimais do token
+    pub fn decimals(env: Env) -> Result<u32, Error> {
+        Ok(read_metadata(&env)?.decimal)
+    }
+
+    // Nome do token
+    pub fn name(env: Env) -> Result<String, Error> {
+        Ok(read_metadata(&env)?.name)
+    }
+
+    // Símbolo do token
+    pub fn symbol(env: Env) -> Result<String, Error> {
+        Ok(read_metadata(&env)?.symbol)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::{contracttype, BytesN, Env, String};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+
+// Espelha `SportType` do backend
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SportType {
+    Football,
+    Basketball,
+    Tennis,
+    Swimming,
+    Athletics,
+    Volleyball,
+    Paralympic,
+}
+
+// Espelha `AthleteLevel` do backend
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AthleteLevel {
+    Amateur,
+    SemiProfessional,
+    Professional,
+    Elite,
+}
+
+// Metadados imutáveis do token e do atleta que ele representa
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    pub decimal: u32,
+    pub name: String,
+    pub symbol: String,
+    pub sport: SportType,
+    pub level: AthleteLevel,
+    // Código ISO do país (2 ou 3 letras)
+    pub country: String,
+    // URI do perfil off-chain e o hash SHA-256 do seu conteúdo
+    pub profile_uri: String,
+    pub profile_hash: BytesN<32>,
+}
+
+// Valida os metadados recebidos na inicialização
+pub fn check_metadata(metadata: &TokenMetadata) -> Result<(), Error> {
+    if metadata.decimal > 18 {
+        return Err(Error::InvalidDecimals);
+    }
+    if metadata.name.is_empty()
+        || metadata.symbol.is_empty()
+        || !(2..=3).contains(&metadata.country.len())
+    {
+        return Err(Error::InvalidMetadata);
+    }
+    Ok(())
+}
+
+// Lê os metadados do token
+pub fn read_metadata(env: &Env) -> Result<TokenMetadata, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Metadata)
+        .ok_or(Error::NotInitialized)
+}
+
+// Grava os metadados do token
+pub fn write_metadata(env: &Env, metadata: &TokenMetadata) {
+    env.storage().instance().set(&DataKey::Metadata, metadata);
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{BytesN, Env};
+
+use crate::storage_types::DataKey;
+use crate::ttl::extend_persistent;
+
+// Indica se a operação já foi aplicada
+pub fn is_processed(env: &Env, op_id: &BytesN<32>) -> bool {
+    let key = DataKey::Operation(op_id.clone());
+    let processed = env.storage().persistent().has(&key);
+    if processed {
+        extend_persistent(env, &key);
+    }
+    processed
+}
+
+// Marca a operação como aplicada. Retorna `false` se ela já havia sido
+// consumida (reenvio de uma transação que já entrou no ledger).
+pub fn consume_operation(env: &Env, op_id: &BytesN<32>) -> bool {
+    if is_processed(env, op_id) {
+        return false;
+    }
+    let key = DataKey::Operation(op_id.clone());
+    env.storage().persistent().set(&key, &true);
+    extend_persistent(env, &key);
+    true
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use crate::ttl::extend_persistent;
+
+// Transferências acima de `threshold` aguardam aprovação de compliance e
+// expiram após `expiry_ledgers`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LargeTransferConfig {
+    pub threshold: i128,
+    pub expiry_ledgers: u32,
+}
+
+// Transferência em custódia no contrato aguardando aprovação
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingTransfer {
+    pub from: Address,
+    pub to: Address,
+    // Id muxed do destinatário (SEP-23), repassado no evento da liberação
+    pub to_muxed_id: Option<u64>,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+pub fn read_large_transfer_config(env: &Env) -> Option<LargeTransferConfig> {
+    env.storage().instance().get(&DataKey::LargeTransferConfig)
+}
+
+pub fn write_large_transfer_config(
+    env: &Env,
+    config: &Option<LargeTransferConfig>,
+) -> Result<(), Error> {
+    match config {
+        Some(config) => {
+            if config.threshold <= 0 || config.expiry_ledgers == 0 {
+                return Err(Error::InvalidLargeTransferConfig);
+            }
+            env.storage()
+                .instance()
+                .set(&DataKey::LargeTransferConfig, config);
+        }
+        None => env
+            .storage()
+            .instance()
+            .remove(&DataKey::LargeTransferConfig),
+    }
+    Ok(())
+}
+
+// Indica se `amount` precisa passar pela fila de aprovação
+pub fn requires_approval(env: &Env, amount: i128) -> bool {
+    read_large_transfer_config(env).is_some_and(|config| amount > config.threshold)
+}
+
+// Registra uma transferência pendente e retorna seu id
+pub fn create_pending(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    to_muxed_id: Option<u64>,
+    amount: i128,
+) -> Result<(u64, PendingTransfer), Error> {
+    let config = read_large_transfer_config(env).ok_or(Error::InvalidLargeTransferConfig)?;
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextPendingId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextPendingId, &(id + 1));
+
+    let pending = PendingTransfer {
+        from: from.clone(),
+        to: to.clone(),
+        to_muxed_id,
+        amount,
+        expiration_ledger: env
+            .ledger()
+            .sequence()
+            .saturating_add(config.expiry_ledgers),
+    };
+    let key = DataKey::Pending(id);
+    env.storage().persistent().set(&key, &pending);
+    extend_persistent(env, &key);
+    let locked = read_locked(env, from)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_locked(env, from, locked);
+    Ok((id, pending))
+}
+
+pub fn read_pending(env: &Env, id: u64) -> Result<PendingTransfer, Error> {
+    let key = DataKey::Pending(id);
+    let pending = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::PendingNotFound)?;
+    extend_persistent(env, &key);
+    Ok(pending)
+}
+
+// Remove a transferência pendente e libera o valor travado do remetente
+pub fn remove_pending(env: &Env, id: u64, pending: &PendingTransfer) -> Result<(), Error> {
+    env.storage().persistent().remove(&DataKey::Pending(id));
+    let locked = read_locked(env, &pending.from)
+        .checked_sub(pending.amount)
+        .ok_or(Error::Overflow)?;
+    write_locked(env, &pending.from, locked);
+    Ok(())
+}
+
+// Total que o titular tem em custódia aguardando aprovação
+pub fn read_locked(env: &Env, account: &Address) -> i128 {
+    let key = DataKey::Locked(account.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(locked) => {
+            extend_persistent(env, &key);
+            locked
+        }
+        None => 0,
+    }
+}
+
+fn write_locked(env: &Env, account: &Address, amount: i128) {
+    let key = DataKey::Locked(account.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        extend_persistent(env, &key);
+    }
+}
+
+pub fn is_expired(env: &Env, pending: &PendingTransfer) -> bool {
+    env.ledger().sequence() > pending.expiration_ledger
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::contracttype;
+
+// Visão consolidada de um titular
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderPosition {
+    pub balance: i128,
+    // Valor em custódia aguardando aprovação de transferência grande
+    pub locked: i128,
+    // Receita a resgatar; `None` enquanto a distribuição for feita off-chain,
+    // para não ser confundido com um saldo zerado
+    pub claimable_revenue: Option<i128>,
+    pub frozen: bool,
+    // Se a conta pode receber tokens (KYC)
+    pub allowed: bool,
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::admin::read_admin;
+use crate::error::Error;
+use crate::storage_types::{DataKey, RoleDataKey};
+use crate::ttl::extend_persistent;
+
+// Papéis operacionais do contrato
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Minter,
+    Pauser,
+    RevenueManager,
+    Compliance,
+    Controller,
+    KycOfficer,
+}
+
+fn role_key(account: &Address, role: Role) -> DataKey {
+    DataKey::Role(RoleDataKey {
+        account: account.clone(),
+        role,
+    })
+}
+
+// Indica se `account` possui `role`
+pub fn has_role(env: &Env, account: &Address, role: Role) -> bool {
+    let key = role_key(account, role);
+    let granted = env.storage().persistent().has(&key);
+    if granted {
+        extend_persistent(env, &key);
+    }
+    granted
+}
+
+pub fn write_role(env: &Env, account: &Address, role: Role) {
+    let key = role_key(account, role);
+    env.storage().persistent().set(&key, &true);
+    extend_persistent(env, &key);
+}
+
+pub fn remove_role(env: &Env, account: &Address, role: Role) {
+    env.storage().persistent().remove(&role_key(account, role));
+}
+
+// Papel que administra `role`; sem valor, apenas o administrador do contrato
+pub fn read_role_admin(env: &Env, role: Role) -> Option<Role> {
+    env.storage().instance().get(&DataKey::RoleAdmin(role))
+}
+
+pub fn write_role_admin(env: &Env, role: Role, admin_role: Role) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RoleAdmin(role), &admin_role);
+}
+
+// Exige a assinatura de `account` e que ele possua `role`
+pub fn require_role(env: &Env, account: &Address, role: Role) -> Result<(), Error> {
+    account.require_auth();
+    if !has_role(env, account, role) {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+// Exige a assinatura de `caller` e que ele possa conceder/revogar `role`:
+// o administrador do contrato ou quem possui o papel administrador de `role`
+pub fn require_role_manager(env: &Env, caller: &Address, role: Role) -> Result<(), Error> {
+    caller.require_auth();
+    if read_admin(env).is_ok_and(|admin| admin == *caller) {
+        return Ok(());
+    }
+    match read_role_admin(env, role) {
+        Some(admin_role) if has_role(env, caller, admin_role) => Ok(()),
+        _ => Err(Error::Unauthorized),
+    }
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use crate::ttl::extend_persistent;
+
+// Valor de um saldo (ou da oferta) no momento em que o snapshot foi tirado
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub snapshot_id: u32,
+    pub value: i128,
+}
+
+// Id do snapshot mais recente (0 se nenhum foi tirado)
+pub fn read_current_snapshot(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CurrentSnapshot)
+        .unwrap_or(0)
+}
+
+// Abre um novo snapshot em O(1); os valores são gravados sob demanda
+pub fn create_snapshot(env: &Env) -> u32 {
+    let id = read_current_snapshot(env) + 1;
+    env.storage().instance().set(&DataKey::CurrentSnapshot, &id);
+    id
+}
+
+// Sequência de checkpoints de um saldo ou da oferta. Cada checkpoint fica em
+// uma entrada própria, indexada de 0 até o contador, para que registrar um
+// novo valor não regrave o histórico inteiro.
+enum Series<'a> {
+    Balance(&'a Address),
+    Supply,
+}
+
+impl Series<'_> {
+    fn count_key(&self) -> DataKey {
+        match self {
+            Series::Balance(addr) => DataKey::BalanceCheckpointCount((*addr).clone()),
+            Series::Supply => DataKey::SupplyCheckpointCount,
+        }
+    }
+
+    fn entry_key(&self, index: u32) -> DataKey {
+        match self {
+            Series::Balance(addr) => DataKey::BalanceCheckpoint((*addr).clone(), index),
+            Series::Supply => DataKey::SupplyCheckpoint(index),
+        }
+    }
+
+    fn count(&self, env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&self.count_key())
+            .unwrap_or(0)
+    }
+
+    fn entry(&self, env: &Env, index: u32) -> Result<Checkpoint, Error> {
+        env.storage()
+            .persistent()
+            .get(&self.entry_key(index))
+            .ok_or(Error::InvalidSnapshot)
+    }
+}
+
+// Antes de alterar um valor, guarda o valor anterior se ele ainda não foi
+// registrado para o snapshot corrente
+fn update_checkpoints(env: &Env, series: Series, previous: i128) -> Result<(), Error> {
+    let current = read_current_snapshot(env);
+    if current == 0 {
+        return Ok(());
+    }
+    let count = series.count(env);
+    if count > 0 && series.entry(env, count - 1)?.snapshot_id >= current {
+        return Ok(());
+    }
+    // Um checkpoint por snapshot: o contador nunca passa do id corrente
+    let entry_key = series.entry_key(count);
+    let checkpoint = Checkpoint {
+        snapshot_id: current,
+        value: previous,
+    };
+    env.storage().persistent().set(&entry_key, &checkpoint);
+    extend_persistent(env, &entry_key);
+    let count_key = series.count_key();
+    env.storage().persistent().set(&count_key, &(count + 1));
+    extend_persistent(env, &count_key);
+    Ok(())
+}
+
+pub fn record_balance_checkpoint(env: &Env, addr: &Address, previous: i128) -> Result<(), Error> {
+    update_checkpoints(env, Series::Balance(addr), previous)
+}
+
+pub fn record_supply_checkpoint(env: &Env, previous: i128) -> Result<(), Error> {
+    update_checkpoints(env, Series::Supply, previous)
+}
+
+// Valor registrado para `snapshot_id`: o primeiro checkpoint com id >= snapshot_id,
+// localizado por busca binária sobre as entradas indexadas.
+// `None` significa que o valor não mudou desde então (vale o valor atual).
+fn value_at(env: &Env, series: Series, snapshot_id: u32) -> Result<Option<i128>, Error> {
+    if snapshot_id == 0 || snapshot_id > read_current_snapshot(env) {
+        return Err(Error::InvalidSnapshot);
+    }
+    let count = series.count(env);
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if series.entry(env, mid)?.snapshot_id < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low == count {
+        return Ok(None);
+    }
+    extend_persistent(env, &series.count_key());
+    extend_persistent(env, &series.entry_key(low));
+    Ok(Some(series.entry(env, low)?.value))
+}
+
+pub fn balance_at(
+    env: &Env,
+    addr: &Address,
+    snapshot_id: u32,
+    current: i128,
+) -> Result<i128, Error> {
+    Ok(value_at(env, Series::Balance(addr), snapshot_id)?.unwrap_or(current))
+}
+
+pub fn total_supply_at(env: &Env, snapshot_id: u32, current: i128) -> Result<i128, Error> {
+    Ok(value_at(env, Series::Supply, snapshot_id)?.unwrap_or(current))
+}
@@ -0,0 +1,84 @@
+use soroban_sdk::{contracttype, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use crate::supply::read_total_supply;
+
+// Ciclo de vida do token, espelhando `TokenStatus` do backend
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenStatus {
+    Draft,
+    Active,
+    Paused,
+    Completed,
+}
+
+pub fn read_status(env: &Env) -> Result<TokenStatus, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Status)
+        .ok_or(Error::NotInitialized)
+}
+
+pub fn write_status(env: &Env, status: TokenStatus) {
+    env.storage().instance().set(&DataKey::Status, &status);
+}
+
+// Alocação inicial que pode ser emitida ainda em Draft
+pub fn read_initial_allocation(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::InitialAllocation)
+        .unwrap_or(0)
+}
+
+pub fn write_initial_allocation(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::InitialAllocation, &amount);
+}
+
+// Aplica uma transição, aceitando apenas Draft→Active→Paused↔Active→Completed.
+// Retorna o status anterior.
+pub fn transition(env: &Env, to: TokenStatus) -> Result<TokenStatus, Error> {
+    let from = read_status(env)?;
+    let allowed = matches!(
+        (from, to),
+        (TokenStatus::Draft, TokenStatus::Active)
+            | (TokenStatus::Active, TokenStatus::Paused)
+            | (TokenStatus::Paused, TokenStatus::Active)
+            | (TokenStatus::Active, TokenStatus::Completed)
+    );
+    if !allowed {
+        return Err(Error::InvalidStatusTransition);
+    }
+    write_status(env, to);
+    Ok(from)
+}
+
+// Movimentações de saldo ficam bloqueadas com o token pausado
+pub fn check_not_paused(env: &Env) -> Result<(), Error> {
+    if read_status(env)? == TokenStatus::Paused {
+        return Err(Error::Paused);
+    }
+    Ok(())
+}
+
+// Em Draft só a alocação inicial pode ser emitida; Completed encerra a emissão primária
+pub fn check_can_mint(env: &Env, amount: i128) -> Result<(), Error> {
+    match read_status(env)? {
+        TokenStatus::Active => Ok(()),
+        TokenStatus::Paused => Err(Error::Paused),
+        TokenStatus::Completed => Err(Error::IssuanceClosed),
+        TokenStatus::Draft => {
+            let minted = read_total_supply(env)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            if minted > read_initial_allocation(env) {
+                return Err(Error::InitialAllocationExceeded);
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+use crate::roles::Role;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleDataKey {
+    pub account: Address,
+    pub role: Role,
+}
+
+// Chaves de armazenamento do contrato
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Balance(Address),
+    Allowance(AllowanceDataKey),
+    Admin,
+    PendingAdmin,
+    Metadata,
+    TtlConfig,
+    TotalSupply,
+    MaxSupply,
+    Version,
+    Role(RoleDataKey),
+    RoleAdmin(Role),
+    Status,
+    InitialAllocation,
+    Frozen(Address),
+    AllowlistEnabled,
+    Allowed(Address),
+    HoldingCap,
+    CapExempt(Address),
+    VelocityLimit,
+    Velocity(Address),
+    LargeTransferConfig,
+    NextPendingId,
+    Pending(u64),
+    Locked(Address),
+    Operation(BytesN<32>),
+    HolderCount,
+    HolderAt(u32),
+    HolderIndex(Address),
+    CurrentSnapshot,
+    BalanceCheckpoint(Address, u32),
+    BalanceCheckpointCount(Address),
+    SupplyCheckpoint(u32),
+    SupplyCheckpointCount,
+}
@@ -0,0 +1,58 @@
+use soroban_sdk::Env;
+
+use crate::error::Error;
+use crate::snapshots::record_supply_checkpoint;
+use crate::storage_types::DataKey;
+
+// Oferta total em circulação
+pub fn read_total_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalSupply)
+        .unwrap_or(0)
+}
+
+fn write_total_supply(env: &Env, amount: i128) -> Result<(), Error> {
+    record_supply_checkpoint(env, read_total_supply(env))?;
+    env.storage().instance().set(&DataKey::TotalSupply, &amount);
+    Ok(())
+}
+
+// Oferta máxima definida na inicialização
+pub fn read_max_supply(env: &Env) -> Result<i128, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxSupply)
+        .ok_or(Error::NotInitialized)
+}
+
+pub fn write_max_supply(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::MaxSupply, &amount);
+}
+
+// Aumenta a oferta respeitando o teto
+pub fn increase_supply(env: &Env, amount: i128) -> Result<(), Error> {
+    let supply = read_total_supply(env)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    if supply > read_max_supply(env)? {
+        return Err(Error::MaxSupplyExceeded);
+    }
+    write_total_supply(env, supply)
+}
+
+// Contabiliza saldos que já existiam antes do controle de oferta (sem checar o teto)
+pub fn record_existing_supply(env: &Env, amount: i128) -> Result<(), Error> {
+    let supply = read_total_supply(env)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_total_supply(env, supply)
+}
+
+// Reduz a oferta após uma queima
+pub fn decrease_supply(env: &Env, amount: i128) -> Result<(), Error> {
+    let supply = read_total_supply(env)
+        .checked_sub(amount)
+        .ok_or(Error::Overflow)?;
+    write_total_supply(env, supply)
+}
@@ -0,0 +1,1221 @@
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::events::Event;
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger, MuxedAddress as _,
+};
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, MuxedAddress, String, Symbol, Val, Vec};
+
+use crate::events::{TransferMuxed, TransferQueued};
+use crate::storage_types::DataKey;
+use crate::MAX_BATCH_SIZE;
+use crate::{
+    AthleteLevel, AthleteToken, AthleteTokenClient, EnforcementReason, Error, HoldingCap,
+    LargeTransferConfig, ReasonCode, Role, SportType, TokenMetadata, TokenStatus, VelocityLimit,
+};
+
+const MAX_SUPPLY: i128 = 1_000_000;
+const THRESHOLD: i128 = 1_000;
+const EXPIRY_LEDGERS: u32 = 100;
+
+fn metadata(env: &Env) -> TokenMetadata {
+    TokenMetadata {
+        decimal: 7,
+        name: String::from_str(env, "Atleta"),
+        symbol: String::from_str(env, "ATL"),
+        sport: SportType::Football,
+        level: AthleteLevel::Professional,
+        country: String::from_str(env, "BR"),
+        profile_uri: String::from_str(env, "ipfs://perfil"),
+        profile_hash: BytesN::from_array(env, &[0; 32]),
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: AthleteTokenClient<'a>,
+    admin: Address,
+    officer: Address,
+    next_op: u8,
+}
+
+impl Setup<'_> {
+    fn new() -> Self {
+        let s = Self::draft(0);
+        s.client.activate();
+        s
+    }
+
+    // Contrato ainda em Draft, com a alocação inicial informada
+    fn draft(initial_allocation: i128) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = AthleteTokenClient::new(&env, &env.register(AthleteToken, ()));
+        let admin = Address::generate(&env);
+        let officer = Address::generate(&env);
+        client.initialize(&admin, &metadata(&env), &MAX_SUPPLY, &initial_allocation);
+        client.grant_role(&admin, &admin, &Role::Minter);
+        client.grant_role(&admin, &officer, &Role::Compliance);
+
+        Setup {
+            env,
+            client,
+            admin,
+            officer,
+            next_op: 0,
+        }
+    }
+
+    // Id de operação ainda não usado
+    fn op_id(&mut self) -> BytesN<32> {
+        self.next_op += 1;
+        BytesN::from_array(&self.env, &[self.next_op; 32])
+    }
+
+    fn mint(&mut self, to: &Address, amount: i128) {
+        let op_id = self.op_id();
+        assert!(self.client.mint(&self.admin, to, &amount, &op_id));
+    }
+
+    fn enable_queue(&self) {
+        self.client
+            .set_large_transfer_config(&Some(LargeTransferConfig {
+                threshold: THRESHOLD,
+                expiry_ledgers: EXPIRY_LEDGERS,
+            }));
+    }
+
+    // Envia uma transferência acima do limite, que deve ir para a custódia.
+    // Os ids pendentes são sequenciais a partir de 0.
+    fn queue(&self, from: &Address, to: &Address, amount: i128) {
+        let escrow = &self.client.address;
+        let before = self.client.balance(escrow);
+        self.client.transfer(from, to, &amount);
+        assert_eq!(self.client.balance(escrow), before + amount);
+    }
+
+    // Verifica que a última invocação do contrato publicou `event`
+    fn assert_published(&self, event: &impl Event) {
+        let expected = (
+            self.client.address.clone(),
+            event.topics(&self.env),
+            event.data(&self.env),
+        );
+        assert!(self.env.events().all().contains(expected));
+    }
+
+    // Verifica que a última invocação exigiu somente a assinatura de `signer`
+    // sobre `function` chamada com `args`
+    fn assert_auth(&self, signer: &Address, function: &str, args: impl IntoVal<Env, Vec<Val>>) {
+        assert_eq!(
+            self.env.auths(),
+            std::vec![(
+                signer.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        self.client.address.clone(),
+                        Symbol::new(&self.env, function),
+                        args.into_val(&self.env),
+                    )),
+                    sub_invocations: std::vec![],
+                }
+            )]
+        );
+    }
+
+    fn advance_ledgers(&self, ledgers: u32) {
+        self.env
+            .ledger()
+            .with_mut(|ledger| ledger.sequence_number += ledgers);
+    }
+
+    fn advance_time(&self, seconds: u64) {
+        self.env
+            .ledger()
+            .with_mut(|ledger| ledger.timestamp += seconds);
+    }
+}
+
+#[test]
+fn test_small_transfer_skips_queue() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.client.transfer(&from, &to, &THRESHOLD);
+    assert_eq!(s.client.balance(&to), THRESHOLD);
+    assert_eq!(s.client.position(&from).locked, 0);
+    assert_eq!(s.client.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_approve_pending_releases_escrow_to_recipient() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.queue(&from, &to, 2_000);
+    let id = 0;
+    assert_eq!(s.client.balance(&from), 3_000);
+    assert_eq!(s.client.balance(&to), 0);
+    assert_eq!(s.client.position(&from).locked, 2_000);
+
+    s.client.approve_pending(&s.officer, &id);
+    assert_eq!(s.client.balance(&to), 2_000);
+    assert_eq!(s.client.balance(&s.client.address), 0);
+    assert_eq!(s.client.position(&from).locked, 0);
+    assert_eq!(
+        s.client.try_pending_transfer(&id),
+        Err(Ok(Error::PendingNotFound))
+    );
+}
+
+#[test]
+fn test_reject_pending_returns_tokens_to_sender() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.queue(&from, &to, 2_000);
+    let id = 0;
+    s.client.reject_pending(&s.officer, &id);
+    assert_eq!(s.client.balance(&from), 5_000);
+    assert_eq!(s.client.balance(&to), 0);
+    assert_eq!(s.client.position(&from).locked, 0);
+    assert_eq!(
+        s.client.try_approve_pending(&s.officer, &id),
+        Err(Ok(Error::PendingNotFound))
+    );
+}
+
+#[test]
+fn test_only_compliance_decides_pending() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.queue(&from, &to, 2_000);
+    let id = 0;
+    assert_eq!(
+        s.client.try_approve_pending(&from, &id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_reject_pending(&to, &id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.client.position(&from).locked, 2_000);
+}
+
+#[test]
+fn test_cancel_pending_returns_tokens_to_sender() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.queue(&from, &to, 2_000);
+    let id = 0;
+    s.client.cancel_pending(&id);
+    assert_eq!(s.client.balance(&from), 5_000);
+    assert_eq!(s.client.position(&from).locked, 0);
+    assert_eq!(s.client.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_expire_pending_only_after_expiration() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.queue(&from, &to, 2_000);
+    let id = 0;
+    s.advance_ledgers(EXPIRY_LEDGERS);
+    assert_eq!(
+        s.client.try_expire_pending(&id),
+        Err(Ok(Error::PendingNotExpired))
+    );
+
+    s.advance_ledgers(1);
+    assert_eq!(
+        s.client.try_approve_pending(&s.officer, &id),
+        Err(Ok(Error::PendingExpired))
+    );
+    s.client.expire_pending(&id);
+    assert_eq!(s.client.balance(&from), 5_000);
+    assert_eq!(s.client.balance(&to), 0);
+    assert_eq!(s.client.position(&from).locked, 0);
+}
+
+#[test]
+fn test_locked_accumulates_across_pending_transfers() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 10_000);
+    s.enable_queue();
+
+    s.queue(&from, &to, 2_000);
+    s.queue(&from, &to, 3_000);
+    assert_eq!(s.client.position(&from).locked, 5_000);
+    assert_eq!(s.client.pending_transfer(&1).amount, 3_000);
+
+    s.client.approve_pending(&s.officer, &0);
+    assert_eq!(s.client.position(&from).locked, 3_000);
+    s.client.cancel_pending(&1);
+    assert_eq!(s.client.position(&from).locked, 0);
+    assert_eq!(s.client.balance(&from), 8_000);
+    assert_eq!(s.client.balance(&to), 2_000);
+}
+
+#[test]
+fn test_queued_transfer_keeps_muxed_id() {
+    let mut s = Setup::new();
+    let from = Address::generate(&s.env);
+    let to = MuxedAddress::new(MuxedAddress::generate(&s.env), 42);
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.client.transfer(&from, &to, &2_000);
+    s.assert_published(&TransferQueued {
+        id: 0,
+        from: from.clone(),
+        to: to.address(),
+        to_muxed_id: Some(42),
+        amount: 2_000,
+        expiration_ledger: s.env.ledger().sequence() + EXPIRY_LEDGERS,
+    });
+    let pending = s.client.pending_transfer(&0);
+    assert_eq!(pending.to, to.address());
+    assert_eq!(pending.to_muxed_id, Some(42));
+
+    s.client.approve_pending(&s.officer, &0);
+    s.assert_published(&TransferMuxed {
+        from: s.client.address.clone(),
+        to: to.address(),
+        to_muxed_id: 42,
+        amount: 2_000,
+    });
+    assert_eq!(s.client.balance(&to.address()), 2_000);
+}
+
+#[test]
+fn test_transfers_to_escrow_are_rejected() {
+    let mut s = Setup::new();
+    let (from, spender) = (Address::generate(&s.env), Address::generate(&s.env));
+    let escrow = s.client.address.clone();
+    s.mint(&from, 5_000);
+    s.client.approve(&from, &spender, &5_000, &1_000);
+
+    assert_eq!(
+        s.client.try_transfer(&from, &escrow, &500),
+        Err(Ok(Error::EscrowAccount))
+    );
+    assert_eq!(
+        s.client.try_transfer_from(&spender, &from, &escrow, &500),
+        Err(Ok(Error::EscrowAccount))
+    );
+    assert_eq!(
+        s.client.try_batch_transfer(
+            &from,
+            &vec![&s.env, (Address::generate(&s.env), 1), (escrow.clone(), 1)]
+        ),
+        Err(Ok(Error::EscrowAccount))
+    );
+    // Acima do limite a transferência também não entra na fila
+    s.enable_queue();
+    assert_eq!(
+        s.client.try_transfer(&from, &escrow, &2_000),
+        Err(Ok(Error::EscrowAccount))
+    );
+    assert_eq!(s.client.balance(&from), 5_000);
+    assert_eq!(s.client.balance(&escrow), 0);
+}
+
+#[test]
+fn test_mints_to_escrow_are_rejected() {
+    let mut s = Setup::new();
+    let escrow = s.client.address.clone();
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &escrow, &100, &op_id),
+        Err(Ok(Error::EscrowAccount))
+    );
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_batch_mint(
+            &s.admin,
+            &vec![
+                &s.env,
+                (Address::generate(&s.env), 100),
+                (escrow.clone(), 100)
+            ],
+            &op_id
+        ),
+        Err(Ok(Error::EscrowAccount))
+    );
+    assert_eq!(s.client.balance(&escrow), 0);
+    assert_eq!(s.client.total_supply(), 0);
+}
+
+#[test]
+fn test_forced_transfer_to_escrow_is_rejected() {
+    let mut s = Setup::new();
+    s.client.grant_role(&s.admin, &s.admin, &Role::Controller);
+    let from = Address::generate(&s.env);
+    let escrow = s.client.address.clone();
+    s.mint(&from, 1_000);
+
+    let reason = EnforcementReason {
+        code: ReasonCode::CourtOrder,
+        document_hash: BytesN::from_array(&s.env, &[7; 32]),
+    };
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client
+            .try_forced_transfer(&s.admin, &from, &escrow, &500, &reason, &op_id),
+        Err(Ok(Error::EscrowAccount))
+    );
+    assert_eq!(s.client.balance(&escrow), 0);
+}
+
+#[test]
+fn test_removing_middle_holder_moves_last_into_its_slot() {
+    let mut s = Setup::new();
+    let holders: [Address; 4] = core::array::from_fn(|_| Address::generate(&s.env));
+    for holder in holders.iter() {
+        s.mint(holder, 100);
+    }
+    assert_eq!(s.client.holder_count(), 4);
+
+    // Zerar o segundo titular leva o último para a posição 1
+    let sink = Address::generate(&s.env);
+    s.client.transfer(&holders[1], &sink, &100);
+    assert_eq!(s.client.holder_count(), 4);
+    let page = s.client.holders(&0, &10);
+    assert_eq!(page.len(), 4);
+    assert_eq!(page.get(0), Some(holders[0].clone()));
+    assert_eq!(page.get(1), Some(holders[3].clone()));
+    assert_eq!(page.get(2), Some(holders[2].clone()));
+    assert_eq!(page.get(3), Some(sink.clone()));
+    assert!(!page.contains(&holders[1]));
+
+    // O titular movido continua removível pela nova posição
+    s.client.burn(&holders[3], &100);
+    let page = s.client.holders(&0, &10);
+    assert_eq!(s.client.holder_count(), 3);
+    assert_eq!(page.get(1), Some(sink.clone()));
+    assert!(!page.contains(&holders[3]));
+}
+
+#[test]
+fn test_removing_last_holder_empties_index() {
+    let mut s = Setup::new();
+    let holder = Address::generate(&s.env);
+    s.mint(&holder, 100);
+
+    s.client.burn(&holder, &100);
+    assert_eq!(s.client.holder_count(), 0);
+    assert_eq!(s.client.holders(&0, &10).len(), 0);
+
+    s.mint(&holder, 50);
+    assert_eq!(s.client.holders(&0, &10).get(0), Some(holder));
+}
+
+#[test]
+fn test_missing_holder_slot_is_reported() {
+    let mut s = Setup::new();
+    let holders: [Address; 3] = core::array::from_fn(|_| Address::generate(&s.env));
+    for holder in holders.iter() {
+        s.mint(holder, 100);
+    }
+    s.env.as_contract(&s.client.address, || {
+        s.env.storage().persistent().remove(&DataKey::HolderAt(1));
+    });
+
+    assert_eq!(
+        s.client.try_holders(&0, &10),
+        Err(Ok(Error::InconsistentHolderIndex))
+    );
+    assert_eq!(s.client.holders(&2, &10).len(), 1);
+}
+
+#[test]
+fn test_escrow_is_not_indexed_as_holder() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 5_000);
+    s.enable_queue();
+
+    s.queue(&from, &to, 2_000);
+    assert_eq!(s.client.holder_count(), 1);
+    s.client.approve_pending(&s.officer, &0);
+    assert_eq!(s.client.holder_count(), 2);
+}
+
+#[test]
+fn test_balance_at_across_snapshots_without_changes() {
+    let mut s = Setup::new();
+    s.client
+        .grant_role(&s.admin, &s.admin, &Role::RevenueManager);
+    let (alice, bob) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&alice, 1_000);
+
+    // Snapshots 1 a 3 sem nenhuma alteração entre eles
+    for expected in 1..=3 {
+        assert_eq!(s.client.snapshot(&s.admin), expected);
+    }
+    s.client.transfer(&alice, &bob, &400);
+    // Snapshots 4 e 5, de novo sem alterações; depois uma nova movimentação
+    s.client.snapshot(&s.admin);
+    s.client.snapshot(&s.admin);
+    s.client.transfer(&alice, &bob, &100);
+    s.mint(&bob, 500);
+
+    for id in 1..=3 {
+        assert_eq!(s.client.balance_at(&alice, &id), 1_000);
+        assert_eq!(s.client.balance_at(&bob, &id), 0);
+        assert_eq!(s.client.total_supply_at(&id), 1_000);
+    }
+    for id in 4..=5 {
+        assert_eq!(s.client.balance_at(&alice, &id), 600);
+        assert_eq!(s.client.balance_at(&bob, &id), 400);
+        assert_eq!(s.client.total_supply_at(&id), 1_000);
+    }
+    assert_eq!(s.client.balance(&alice), 500);
+    assert_eq!(s.client.balance(&bob), 1_000);
+    assert_eq!(s.client.total_supply(), 1_500);
+}
+
+#[test]
+fn test_snapshot_values_default_to_current_when_untouched() {
+    let mut s = Setup::new();
+    s.client
+        .grant_role(&s.admin, &s.admin, &Role::RevenueManager);
+    let holder = Address::generate(&s.env);
+    s.mint(&holder, 700);
+
+    s.client.snapshot(&s.admin);
+    s.client.snapshot(&s.admin);
+    assert_eq!(s.client.balance_at(&holder, &1), 700);
+    assert_eq!(s.client.balance_at(&holder, &2), 700);
+    assert_eq!(s.client.total_supply_at(&2), 700);
+    assert_eq!(
+        s.client.try_balance_at(&holder, &0),
+        Err(Ok(Error::InvalidSnapshot))
+    );
+    assert_eq!(
+        s.client.try_total_supply_at(&3),
+        Err(Ok(Error::InvalidSnapshot))
+    );
+}
+
+#[test]
+fn test_one_checkpoint_per_snapshot() {
+    let mut s = Setup::new();
+    s.client
+        .grant_role(&s.admin, &s.admin, &Role::RevenueManager);
+    let (alice, bob) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&alice, 1_000);
+
+    // Várias alterações no mesmo snapshot preservam o valor da abertura
+    s.client.snapshot(&s.admin);
+    s.client.transfer(&alice, &bob, &100);
+    s.client.transfer(&alice, &bob, &200);
+    s.client.snapshot(&s.admin);
+    s.client.transfer(&bob, &alice, &300);
+    s.client.snapshot(&s.admin);
+    s.client.transfer(&alice, &bob, &50);
+
+    assert_eq!(s.client.balance_at(&alice, &1), 1_000);
+    assert_eq!(s.client.balance_at(&alice, &2), 700);
+    assert_eq!(s.client.balance_at(&alice, &3), 1_000);
+    assert_eq!(s.client.balance_at(&bob, &1), 0);
+    assert_eq!(s.client.balance_at(&bob, &2), 300);
+    assert_eq!(s.client.balance_at(&bob, &3), 0);
+}
+
+#[test]
+fn test_migrate_balances_rejects_negative_legacy_balance() {
+    let mut s = Setup::new();
+    let (good, bad) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&good, 10);
+    s.env.as_contract(&s.client.address, || {
+        s.env.storage().persistent().set(&good, &70_i128);
+        s.env.storage().persistent().set(&bad, &-50_i128);
+    });
+
+    assert_eq!(
+        s.client
+            .try_migrate_balances(&vec![&s.env, good.clone(), bad.clone()]),
+        Err(Ok(Error::InvalidLegacyBalance))
+    );
+    assert_eq!(s.client.balance(&good), 10);
+    assert_eq!(s.client.balance(&bad), 0);
+    assert_eq!(s.client.total_supply(), 10);
+
+    assert_eq!(s.client.migrate_balances(&vec![&s.env, good.clone()]), 1);
+    assert_eq!(s.client.balance(&good), 80);
+    assert_eq!(s.client.total_supply(), 80);
+    assert_eq!(s.client.holder_count(), 1);
+}
+
+#[test]
+fn test_mint_replay_credits_once() {
+    let mut s = Setup::new();
+    let to = Address::generate(&s.env);
+    let op_id = s.op_id();
+    assert!(!s.client.is_processed(&op_id));
+
+    assert!(s.client.mint(&s.admin, &to, &100, &op_id));
+    assert!(s.client.is_processed(&op_id));
+    // Reenvio após timeout: não credita de novo
+    assert!(!s.client.mint(&s.admin, &to, &100, &op_id));
+    assert_eq!(s.client.balance(&to), 100);
+    assert_eq!(s.client.total_supply(), 100);
+
+    // O mesmo id também não vale para um lote
+    assert!(!s
+        .client
+        .batch_mint(&s.admin, &vec![&s.env, (to.clone(), 50)], &op_id));
+    assert_eq!(s.client.balance(&to), 100);
+}
+
+#[test]
+fn test_failed_mint_does_not_consume_operation() {
+    let mut s = Setup::new();
+    let to = Address::generate(&s.env);
+    let op_id = s.op_id();
+    s.client.freeze(&s.officer, &to);
+
+    assert_eq!(
+        s.client.try_mint(&s.admin, &to, &100, &op_id),
+        Err(Ok(Error::AccountFrozen))
+    );
+    assert!(!s.client.is_processed(&op_id));
+    s.client.unfreeze(&s.officer, &to);
+    assert!(s.client.mint(&s.admin, &to, &100, &op_id));
+}
+
+#[test]
+fn test_forced_transfer_replay_moves_once() {
+    let mut s = Setup::new();
+    s.client.grant_role(&s.admin, &s.admin, &Role::Controller);
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+
+    let reason = EnforcementReason {
+        code: ReasonCode::LostKeyRecovery,
+        document_hash: BytesN::from_array(&s.env, &[9; 32]),
+    };
+    let op_id = s.op_id();
+    assert!(s
+        .client
+        .forced_transfer(&s.admin, &from, &to, &400, &reason, &op_id));
+    assert!(!s
+        .client
+        .forced_transfer(&s.admin, &from, &to, &400, &reason, &op_id));
+    assert!(s.client.is_processed(&op_id));
+    assert_eq!(s.client.balance(&from), 600);
+    assert_eq!(s.client.balance(&to), 400);
+    assert_eq!(s.client.total_supply(), 1_000);
+}
+
+#[test]
+fn test_clawback_replay_burns_once() {
+    let mut s = Setup::new();
+    s.client.grant_role(&s.admin, &s.admin, &Role::Controller);
+    let from = Address::generate(&s.env);
+    s.mint(&from, 1_000);
+
+    let reason = EnforcementReason {
+        code: ReasonCode::CourtOrder,
+        document_hash: BytesN::from_array(&s.env, &[3; 32]),
+    };
+    let op_id = s.op_id();
+    assert!(s.client.clawback(&s.admin, &from, &300, &reason, &op_id));
+    assert!(!s.client.clawback(&s.admin, &from, &300, &reason, &op_id));
+    assert_eq!(s.client.balance(&from), 700);
+    assert_eq!(s.client.total_supply(), 700);
+}
+
+#[test]
+fn test_initialize_only_once() {
+    let s = Setup::new();
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_initialize(&other, &metadata(&s.env), &MAX_SUPPLY, &0),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+    assert_eq!(s.client.admin(), s.admin);
+}
+
+#[test]
+fn test_mint_requires_minter_auth() {
+    let mut s = Setup::new();
+    let to = Address::generate(&s.env);
+    let op_id = s.op_id();
+    s.client.mint(&s.admin, &to, &100, &op_id);
+    s.assert_auth(
+        &s.admin,
+        "mint",
+        (s.admin.clone(), to.clone(), 100_i128, op_id.clone()),
+    );
+}
+
+#[test]
+fn test_mint_without_signature_fails() {
+    let mut s = Setup::new();
+    let to = Address::generate(&s.env);
+    let op_id = s.op_id();
+    s.env.set_auths(&[]);
+    // Falha de autorização do host, não um erro do contrato
+    assert!(matches!(
+        s.client.try_mint(&s.admin, &to, &100, &op_id),
+        Err(Err(_))
+    ));
+    assert_eq!(s.client.balance(&to), 0);
+    assert!(!s.client.is_processed(&op_id));
+}
+
+#[test]
+fn test_transfer_requires_sender_auth() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+
+    s.client.transfer(&from, &to, &300);
+    s.assert_auth(&from, "transfer", (from.clone(), to.clone(), 300_i128));
+}
+
+#[test]
+fn test_transfer_without_signature_fails() {
+    let mut s = Setup::new();
+    let (from, thief) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+
+    s.env.set_auths(&[]);
+    assert!(matches!(
+        s.client.try_transfer(&from, &thief, &1_000),
+        Err(Err(_))
+    ));
+    assert_eq!(s.client.balance(&from), 1_000);
+    assert_eq!(s.client.balance(&thief), 0);
+}
+
+#[test]
+fn test_transfer_from_requires_spender_auth_and_allowance() {
+    let mut s = Setup::new();
+    let (from, spender, to) = (
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    );
+    s.mint(&from, 1_000);
+
+    assert_eq!(
+        s.client.try_transfer_from(&spender, &from, &to, &100),
+        Err(Ok(Error::InsufficientAllowance))
+    );
+    s.client.approve(&from, &spender, &150, &1_000);
+    s.client.transfer_from(&spender, &from, &to, &100);
+    s.assert_auth(
+        &spender,
+        "transfer_from",
+        (spender.clone(), from.clone(), to.clone(), 100_i128),
+    );
+    assert_eq!(s.client.allowance(&from, &spender), 50);
+    assert_eq!(
+        s.client.try_transfer_from(&spender, &from, &to, &100),
+        Err(Ok(Error::InsufficientAllowance))
+    );
+    assert_eq!(s.client.balance(&to), 100);
+}
+
+#[test]
+fn test_initialize_grants_no_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = AthleteTokenClient::new(&env, &env.register(AthleteToken, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &metadata(&env), &MAX_SUPPLY, &100);
+
+    assert!(!client.has_role(&admin, &Role::Minter));
+    assert_eq!(
+        client.try_mint(
+            &admin,
+            &Address::generate(&env),
+            &100,
+            &BytesN::from_array(&env, &[1; 32])
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_grant_role_requires_admin_auth() {
+    let s = Setup::new();
+    let minter = Address::generate(&s.env);
+    s.client.grant_role(&s.admin, &minter, &Role::Minter);
+    s.assert_auth(
+        &s.admin,
+        "grant_role",
+        (s.admin.clone(), minter.clone(), Role::Minter),
+    );
+    assert!(s.client.has_role(&minter, &Role::Minter));
+}
+
+#[test]
+fn test_roles_gate_privileged_entrypoints() {
+    let mut s = Setup::new();
+    let (outsider, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    let op_id = s.op_id();
+
+    assert_eq!(
+        s.client.try_mint(&outsider, &to, &100, &op_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_grant_role(&outsider, &outsider, &Role::Minter),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_freeze(&outsider, &to),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.client.try_pause(&outsider), Err(Ok(Error::Unauthorized)));
+    // Compliance não herda o papel de Minter
+    assert_eq!(
+        s.client.try_mint(&s.officer, &to, &100, &op_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(!s.client.is_processed(&op_id));
+}
+
+#[test]
+fn test_revoked_minter_cannot_mint() {
+    let mut s = Setup::new();
+    let (minter, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.grant_role(&s.admin, &minter, &Role::Minter);
+    let op_id = s.op_id();
+    assert!(s.client.mint(&minter, &to, &100, &op_id));
+
+    s.client.revoke_role(&s.admin, &minter, &Role::Minter);
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&minter, &to, &100, &op_id),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_role_admin_can_manage_its_role() {
+    let s = Setup::new();
+    let minter = Address::generate(&s.env);
+    s.client.set_role_admin(&Role::Minter, &Role::Compliance);
+
+    s.client.grant_role(&s.officer, &minter, &Role::Minter);
+    assert!(s.client.has_role(&minter, &Role::Minter));
+    // O papel administrador de Minter não gerencia outros papéis
+    assert_eq!(
+        s.client.try_grant_role(&s.officer, &minter, &Role::Pauser),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_mint_respects_max_supply() {
+    let mut s = Setup::new();
+    let to = Address::generate(&s.env);
+    s.mint(&to, MAX_SUPPLY - 100);
+
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &to, &101, &op_id),
+        Err(Ok(Error::MaxSupplyExceeded))
+    );
+    s.mint(&to, 100);
+    assert_eq!(s.client.total_supply(), MAX_SUPPLY);
+
+    // Queimar libera espaço sob o teto
+    s.client.burn(&to, &10);
+    s.mint(&to, 10);
+    assert_eq!(s.client.total_supply(), MAX_SUPPLY);
+    assert_eq!(s.client.max_supply(), MAX_SUPPLY);
+}
+
+#[test]
+fn test_draft_mints_only_initial_allocation() {
+    let mut s = Setup::draft(500);
+    let to = Address::generate(&s.env);
+    assert_eq!(s.client.status(), TokenStatus::Draft);
+
+    s.mint(&to, 400);
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &to, &101, &op_id),
+        Err(Ok(Error::InitialAllocationExceeded))
+    );
+    s.mint(&to, 100);
+
+    s.client.activate();
+    s.mint(&to, 1_000);
+    assert_eq!(s.client.balance(&to), 1_500);
+}
+
+#[test]
+fn test_pause_blocks_movements_and_mint() {
+    let mut s = Setup::new();
+    s.client.grant_role(&s.admin, &s.admin, &Role::Pauser);
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+
+    s.client.pause(&s.admin);
+    assert_eq!(
+        s.client.try_transfer(&from, &to, &100),
+        Err(Ok(Error::Paused))
+    );
+    assert_eq!(s.client.try_burn(&from, &100), Err(Ok(Error::Paused)));
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &to, &100, &op_id),
+        Err(Ok(Error::Paused))
+    );
+
+    s.client.unpause(&s.admin);
+    s.client.transfer(&from, &to, &100);
+    assert_eq!(s.client.balance(&to), 100);
+}
+
+#[test]
+fn test_completed_closes_issuance_but_not_transfers() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+
+    s.client.complete();
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &to, &100, &op_id),
+        Err(Ok(Error::IssuanceClosed))
+    );
+    s.client.transfer(&from, &to, &100);
+    assert_eq!(s.client.balance(&to), 100);
+}
+
+#[test]
+fn test_only_legal_status_transitions() {
+    let s = Setup::draft(0);
+    s.client.grant_role(&s.admin, &s.admin, &Role::Pauser);
+    assert_eq!(
+        s.client.try_pause(&s.admin),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    assert_eq!(
+        s.client.try_complete(),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    s.client.activate();
+    s.client.complete();
+    assert_eq!(
+        s.client.try_activate(),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    assert_eq!(s.client.status(), TokenStatus::Completed);
+}
+
+#[test]
+fn test_frozen_account_cannot_send_or_receive() {
+    let mut s = Setup::new();
+    let (frozen, other) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&frozen, 1_000);
+    s.mint(&other, 1_000);
+
+    s.client.freeze(&s.officer, &frozen);
+    assert!(s.client.is_frozen(&frozen));
+    assert_eq!(
+        s.client.try_transfer(&frozen, &other, &100),
+        Err(Ok(Error::AccountFrozen))
+    );
+    assert_eq!(
+        s.client.try_transfer(&other, &frozen, &100),
+        Err(Ok(Error::AccountFrozen))
+    );
+    assert_eq!(
+        s.client.try_burn(&frozen, &100),
+        Err(Ok(Error::AccountFrozen))
+    );
+
+    s.client.unfreeze(&s.officer, &frozen);
+    assert!(!s.client.is_frozen(&frozen));
+    s.client.transfer(&frozen, &other, &100);
+    assert_eq!(s.client.balance(&other), 1_100);
+}
+
+#[test]
+fn test_allowlist_gates_recipients_until_expiration() {
+    let mut s = Setup::new();
+    s.client.grant_role(&s.admin, &s.officer, &Role::KycOfficer);
+    let (investor, outsider) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.set_allowlist_mode(&true);
+
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &investor, &100, &op_id),
+        Err(Ok(Error::NotAllowlisted))
+    );
+    let expiration = s.env.ledger().sequence() + 10;
+    s.client
+        .approve_holders(&s.officer, &vec![&s.env, investor.clone()], &expiration);
+    assert!(s.client.is_allowed(&investor));
+    s.mint(&investor, 1_000);
+    assert_eq!(
+        s.client.try_transfer(&investor, &outsider, &100),
+        Err(Ok(Error::NotAllowlisted))
+    );
+
+    // A aprovação vale até o ledger de expiração, inclusive
+    s.advance_ledgers(10);
+    assert!(s.client.is_allowed(&investor));
+    s.advance_ledgers(1);
+    assert!(!s.client.is_allowed(&investor));
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &investor, &100, &op_id),
+        Err(Ok(Error::NotAllowlisted))
+    );
+}
+
+#[test]
+fn test_revoked_holder_cannot_receive() {
+    let mut s = Setup::new();
+    s.client.grant_role(&s.admin, &s.officer, &Role::KycOfficer);
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+    s.client.set_allowlist_mode(&true);
+    let expiration = s.env.ledger().sequence() + 1_000;
+    s.client.approve_holders(
+        &s.officer,
+        &vec![&s.env, from.clone(), to.clone()],
+        &expiration,
+    );
+
+    s.client.transfer(&from, &to, &100);
+    s.client
+        .revoke_holders(&s.officer, &vec![&s.env, to.clone()]);
+    assert_eq!(
+        s.client.try_transfer(&from, &to, &100),
+        Err(Ok(Error::NotAllowlisted))
+    );
+    // O modo desligado libera qualquer destinatário
+    s.client.set_allowlist_mode(&false);
+    s.client.transfer(&from, &to, &100);
+    assert_eq!(s.client.balance(&to), 200);
+}
+
+#[test]
+fn test_absolute_holding_cap() {
+    let mut s = Setup::new();
+    let (whale, other) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.set_holding_cap(&HoldingCap::Absolute(1_000));
+
+    s.mint(&whale, 1_000);
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &whale, &1, &op_id),
+        Err(Ok(Error::HoldingCapExceeded))
+    );
+    s.mint(&other, 500);
+    assert_eq!(
+        s.client.try_transfer(&other, &whale, &1),
+        Err(Ok(Error::HoldingCapExceeded))
+    );
+    // Transferência para si mesmo não conta como acúmulo
+    s.client.transfer(&whale, &whale, &1_000);
+    assert_eq!(s.client.balance(&whale), 1_000);
+}
+
+#[test]
+fn test_basis_points_holding_cap() {
+    let mut s = Setup::new();
+    let whale = Address::generate(&s.env);
+    // 1% de MAX_SUPPLY
+    s.client.set_holding_cap(&HoldingCap::BasisPoints(100));
+
+    s.mint(&whale, MAX_SUPPLY / 100);
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &whale, &1, &op_id),
+        Err(Ok(Error::HoldingCapExceeded))
+    );
+    assert_eq!(
+        s.client
+            .try_set_holding_cap(&HoldingCap::BasisPoints(10_001)),
+        Err(Ok(Error::InvalidHoldingCap))
+    );
+}
+
+#[test]
+fn test_cap_exempt_account_ignores_holding_cap() {
+    let mut s = Setup::new();
+    let platform = Address::generate(&s.env);
+    s.client.set_holding_cap(&HoldingCap::Absolute(1_000));
+    s.client.set_cap_exempt(&platform, &true);
+
+    s.mint(&platform, 50_000);
+    assert_eq!(s.client.balance(&platform), 50_000);
+
+    s.client.set_cap_exempt(&platform, &false);
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_mint(&s.admin, &platform, &1, &op_id),
+        Err(Ok(Error::HoldingCapExceeded))
+    );
+}
+
+#[test]
+fn test_velocity_limits_transfer_count_per_window() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+    s.client.set_velocity_limit(&Some(VelocityLimit {
+        window_seconds: 60,
+        max_transfers: 2,
+        max_amount: 0,
+    }));
+
+    s.client.transfer(&from, &to, &10);
+    s.client.transfer(&from, &to, &10);
+    assert_eq!(
+        s.client.try_transfer(&from, &to, &10),
+        Err(Ok(Error::TransferCountExceeded))
+    );
+    // O limite é por remetente
+    s.client.transfer(&to, &from, &10);
+
+    s.advance_time(59);
+    assert_eq!(
+        s.client.try_transfer(&from, &to, &10),
+        Err(Ok(Error::TransferCountExceeded))
+    );
+    s.advance_time(1);
+    s.client.transfer(&from, &to, &10);
+    assert_eq!(s.client.balance(&to), 20);
+}
+
+#[test]
+fn test_velocity_limits_volume_per_window() {
+    let mut s = Setup::new();
+    let (from, to) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.mint(&from, 1_000);
+    s.client.set_velocity_limit(&Some(VelocityLimit {
+        window_seconds: 3_600,
+        max_transfers: 0,
+        max_amount: 500,
+    }));
+
+    s.client.transfer(&from, &to, &300);
+    assert_eq!(
+        s.client.try_transfer(&from, &to, &201),
+        Err(Ok(Error::TransferVolumeExceeded))
+    );
+    s.client.transfer(&from, &to, &200);
+
+    s.advance_time(3_600);
+    s.client.transfer(&from, &to, &500);
+    assert_eq!(s.client.balance(&to), 1_000);
+
+    // Sem limite configurado, nada é contado
+    s.client.set_velocity_limit(&None);
+    s.client.transfer(&to, &from, &1_000);
+}
+
+#[test]
+fn test_batch_mint_is_all_or_nothing() {
+    let mut s = Setup::new();
+    let (first, frozen) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.freeze(&s.officer, &frozen);
+
+    let op_id = s.op_id();
+    let recipients = vec![&s.env, (first.clone(), 100), (frozen.clone(), 100)];
+    assert_eq!(
+        s.client.try_batch_mint(&s.admin, &recipients, &op_id),
+        Err(Ok(Error::AccountFrozen))
+    );
+    assert_eq!(s.client.balance(&first), 0);
+    assert_eq!(s.client.balance(&frozen), 0);
+    assert_eq!(s.client.total_supply(), 0);
+    assert!(!s.client.is_processed(&op_id));
+
+    s.client.unfreeze(&s.officer, &frozen);
+    assert!(s.client.batch_mint(&s.admin, &recipients, &op_id));
+    assert_eq!(s.client.total_supply(), 200);
+}
+
+#[test]
+fn test_batch_transfer_is_all_or_nothing() {
+    let mut s = Setup::new();
+    let (from, first, capped) = (
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    );
+    s.mint(&from, 1_000);
+    s.mint(&capped, 900);
+    s.client.set_holding_cap(&HoldingCap::Absolute(1_000));
+
+    let recipients = vec![&s.env, (first.clone(), 100), (capped.clone(), 200)];
+    assert_eq!(
+        s.client.try_batch_transfer(&from, &recipients),
+        Err(Ok(Error::HoldingCapExceeded))
+    );
+    assert_eq!(s.client.balance(&from), 1_000);
+    assert_eq!(s.client.balance(&first), 0);
+    assert_eq!(s.client.balance(&capped), 900);
+
+    // Saldo insuficiente no meio do lote também desfaz os anteriores
+    let recipients = vec![
+        &s.env,
+        (first.clone(), 600),
+        (Address::generate(&s.env), 600),
+    ];
+    assert_eq!(
+        s.client.try_batch_transfer(&from, &recipients),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(s.client.balance(&from), 1_000);
+    assert_eq!(s.client.balance(&first), 0);
+}
+
+#[test]
+fn test_batch_size_limits() {
+    let mut s = Setup::new();
+    let from = Address::generate(&s.env);
+    s.mint(&from, 1_000);
+
+    let mut recipients = vec![&s.env];
+    assert_eq!(
+        s.client.try_batch_transfer(&from, &recipients),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    for _ in 0..MAX_BATCH_SIZE {
+        recipients.push_back((Address::generate(&s.env), 1));
+    }
+    s.client.batch_transfer(&from, &recipients);
+    assert_eq!(s.client.balance(&from), 1_000 - i128::from(MAX_BATCH_SIZE));
+
+    recipients.push_back((Address::generate(&s.env), 1));
+    let op_id = s.op_id();
+    assert_eq!(
+        s.client.try_batch_mint(&s.admin, &recipients, &op_id),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{contracttype, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Limites de TTL: quando o TTL restante cai abaixo de `*_threshold`,
+// a entrada é estendida até `*_extend_to` ledgers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub instance_threshold: u32,
+    pub instance_extend_to: u32,
+    pub balance_threshold: u32,
+    pub balance_extend_to: u32,
+}
+
+impl Default for TtlConfig {
+    fn default() -> Self {
+        TtlConfig {
+            instance_threshold: INSTANCE_LIFETIME_THRESHOLD,
+            instance_extend_to: INSTANCE_BUMP_AMOUNT,
+            balance_threshold: BALANCE_LIFETIME_THRESHOLD,
+            balance_extend_to: BALANCE_BUMP_AMOUNT,
+        }
+    }
+}
+
+// Lê a configuração de TTL (ou os valores padrão)
+pub fn read_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or_default()
+}
+
+// Grava a configuração de TTL após validar os limites. Limiar zero ou extensão
+// menor que um dia tornariam as extensões inócuas e as entradas seriam arquivadas.
+pub fn write_ttl_config(env: &Env, config: &TtlConfig) -> Result<(), Error> {
+    let max_ttl = env.storage().max_ttl();
+    if config.instance_threshold == 0
+        || config.balance_threshold == 0
+        || config.instance_extend_to < DAY_IN_LEDGERS
+        || config.balance_extend_to < DAY_IN_LEDGERS
+        || config.instance_threshold > config.instance_extend_to
+        || config.balance_threshold > config.balance_extend_to
+        || config.instance_extend_to > max_ttl
+        || config.balance_extend_to > max_ttl
+    {
+        return Err(Error::InvalidTtlConfig);
+    }
+    env.storage().instance().set(&DataKey::TtlConfig, config);
+    Ok(())
+}
+
+// Estende o TTL da instância do contrato
+pub fn extend_instance(env: &Env) {
+    let config = read_ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.instance_threshold, config.instance_extend_to);
+}
+
+// Estende o TTL de uma entrada persistente (saldos e afins)
+pub fn extend_persistent(env: &Env, key: &DataKey) {
+    let config = read_ttl_config(env);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, config.balance_threshold, config.balance_extend_to);
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::Env;
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+
+// Versão do esquema de armazenamento esperada por este código.
+// Incremente ao adicionar um passo em `migrate_step`.
+pub(crate) const CURRENT_VERSION: u32 = 2;
+
+// Versão do esquema gravada no contrato; instâncias sem versão contam como 0
+pub fn read_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+pub fn write_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::Version, &version);
+}
+
+// Transformações de armazenamento para chegar em `to_version`
+fn migrate_step(_env: &Env, to_version: u32) -> Result<(), Error> {
+    match to_version {
+        // v1: layout com `DataKey`; saldos legados são movidos via `migrate_balances`
+        1 => Ok(()),
+        // v2: índice de titulares; quem já tinha saldo é indexado via `index_holders`
+        2 => Ok(()),
+        _ => Err(Error::UnknownVersion),
+    }
+}
+
+// Executa, uma única vez, cada passo pendente até `CURRENT_VERSION`.
+// Retorna a versão anterior.
+pub fn run_migrations(env: &Env) -> Result<u32, Error> {
+    let from = read_version(env);
+    if from >= CURRENT_VERSION {
+        return Err(Error::AlreadyMigrated);
+    }
+    for version in (from + 1)..=CURRENT_VERSION {
+        migrate_step(env, version)?;
+        write_version(env, version);
+    }
+    Ok(from)
+}
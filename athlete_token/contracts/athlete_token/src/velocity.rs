@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::error::Error;
+use crate::storage_types::DataKey;
+
+// Tempo médio de fechamento de um ledger, em segundos
+const LEDGER_SECONDS: u64 = 5;
+
+// Ledgers fecham mais rápido que a média às vezes; a janela não pode expirar
+// antes do tempo, então o TTL cobre o dobro da estimativa
+const TTL_SAFETY_FACTOR: u64 = 2;
+
+// Limites de transferência por remetente em janelas de tempo do ledger.
+// Zero em `max_transfers` ou `max_amount` desativa aquele limite.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityLimit {
+    pub window_seconds: u64,
+    pub max_transfers: u32,
+    pub max_amount: i128,
+}
+
+// Uso do remetente na janela corrente (armazenamento temporário)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityWindow {
+    pub window_start: u64,
+    pub transfers: u32,
+    pub amount: i128,
+}
+
+pub fn read_velocity_limit(env: &Env) -> Option<VelocityLimit> {
+    env.storage().instance().get(&DataKey::VelocityLimit)
+}
+
+pub fn write_velocity_limit(env: &Env, limit: &Option<VelocityLimit>) -> Result<(), Error> {
+    match limit {
+        Some(limit) => {
+            if limit.window_seconds == 0 || limit.max_amount < 0 {
+                return Err(Error::InvalidVelocityLimit);
+            }
+            env.storage().instance().set(&DataKey::VelocityLimit, limit);
+        }
+        None => env.storage().instance().remove(&DataKey::VelocityLimit),
+    }
+    Ok(())
+}
+
+// Contabiliza uma transferência de `from` e falha se algum limite for excedido
+pub fn record_transfer(env: &Env, from: &Address, amount: i128) -> Result<(), Error> {
+    let Some(limit) = read_velocity_limit(env) else {
+        return Ok(());
+    };
+
+    let key = DataKey::Velocity(from.clone());
+    let now = env.ledger().timestamp();
+    let mut window = env
+        .storage()
+        .temporary()
+        .get::<DataKey, VelocityWindow>(&key)
+        .filter(|window| now < window.window_start.saturating_add(limit.window_seconds))
+        .unwrap_or(VelocityWindow {
+            window_start: now,
+            transfers: 0,
+            amount: 0,
+        });
+
+    window.transfers = window.transfers.checked_add(1).ok_or(Error::Overflow)?;
+    window.amount = window.amount.checked_add(amount).ok_or(Error::Overflow)?;
+    if limit.max_transfers > 0 && window.transfers > limit.max_transfers {
+        return Err(Error::TransferCountExceeded);
+    }
+    if limit.max_amount > 0 && window.amount > limit.max_amount {
+        return Err(Error::TransferVolumeExceeded);
+    }
+
+    env.storage().temporary().set(&key, &window);
+    let ledgers = (limit.window_seconds / LEDGER_SECONDS + 1).saturating_mul(TTL_SAFETY_FACTOR);
+    let live_for = u32::try_from(ledgers)
+        .unwrap_or(u32::MAX)
+        .min(env.storage().max_ttl());
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, live_for, live_for);
+    Ok(())
+}
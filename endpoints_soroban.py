@@ -69,6 +69,8 @@ async def health_check():
 class MintRequest(BaseModel):
     to_address: str
     amount: int
+    # `transaction_id` do investimento que originou o mint; vira o op_id do contrato
+    transaction_id: str
 
     @validator("to_address")
     def validate_to_address(cls, v):
@@ -90,6 +92,13 @@ class MintRequest(BaseModel):
             raise ValueError('A quantidade para mintar deve ser positiva')
         return v
 
+    @validator('transaction_id')
+    def validate_transaction_id(cls, v):
+        v = v.strip()
+        if not v:
+            raise ValueError('O transaction_id do investimento é obrigatório')
+        return v
+
 class TransferRequest(BaseModel):
     from_address: str
     to_address: str
@@ -137,8 +146,8 @@ async def mint_tokens(request: MintRequest):
     try:
         logger.info(f"Tentando mintar {request.amount} tokens para {request.to_address}")
         
-        # A função `mint` agora aceita o endereço como string
-        result = await contract_manager.mint(request.to_address, request.amount)
+        # O transaction_id do investimento garante que um reenvio não credite duas vezes
+        result = await contract_manager.mint(request.to_address, request.amount, request.transaction_id)
         
         if result.success:
             logger.info(f"Mint de {request.amount} tokens para {request.to_address} bem-sucedido")
@@ -146,6 +155,7 @@ async def mint_tokens(request: MintRequest):
                 "message": "Tokens mintados com sucesso",
                 "to_address": request.to_address,
                 "amount": request.amount,
+                "transaction_id": request.transaction_id,
                 "transaction_hash": result.transaction_hash
             }
         else:
@@ -159,11 +169,15 @@ async def mint_tokens(request: MintRequest):
 
 @router.post("/transfer")
 async def transfer_tokens(request: TransferRequest):
-    """Transfere tokens de um endereço para outro"""
+    """Transfere tokens de um endereço para outro usando a allowance concedida à plataforma.
+
+    O titular precisa ter aprovado o endereço do admin como spender (`approve`),
+    pois o contrato exige a assinatura de `from` em transferências diretas.
+    """
     try:
         logger.info(f"Tentando transferir {request.amount} tokens de {request.from_address} para {request.to_address}")
         
-        result = await contract_manager.transfer(request.from_address, request.to_address, request.amount)
+        result = await contract_manager.transfer_from(request.from_address, request.to_address, request.amount)
         
         if result.success:
             logger.info(f"Transferência de {request.amount} tokens de {request.from_address} para {request.to_address} bem-sucedida")
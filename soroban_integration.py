@@ -1,4 +1,5 @@
 import asyncio
+import hashlib
 from typing import Dict, Optional, List, Any
 import logging
 from dataclasses import dataclass
@@ -164,27 +165,53 @@ class SorobanContractManager:
         except Exception as e:
             return ContractCallResult(success=False, error_message=str(e))
 
-    async def mint(self, to_address: str, amount: int) -> ContractCallResult:
+    def _operation_id(self, operation_id: str) -> bytes:
+        """Converte o id da operação do backend no `op_id` de 32 bytes do contrato"""
+        return hashlib.sha256(operation_id.encode()).digest()
+
+    async def mint(self, to_address: str, amount: int, operation_id: str) -> ContractCallResult:
         try:
             if amount <= 0:
                 raise ValueError("A quantidade para mintar deve ser positiva")
+            if not operation_id:
+                raise ValueError("O id da operação é obrigatório para o mint")
+            # O mesmo op_id é reutilizado em todas as tentativas, então um reenvio
+            # após timeout não credita o investidor duas vezes
+            op_id = self._operation_id(operation_id)
             args = [
+                scval.to_address(self.admin_keypair.public_key),
                 scval.to_address(to_address),
-                scval.to_int128(amount)
+                scval.to_int128(amount),
+                scval.to_bytes(op_id)
             ]
             return await self._execute_contract_function("mint", args, signer_keypair=self.admin_keypair)
         except Exception as e:
             logger.error(f"Operação de mint falhou: {e}")
             return ContractCallResult(success=False, error_message=str(e))
 
-    async def transfer(self, from_address: str, to_address: str, amount: int) -> ContractCallResult:
+    async def is_processed(self, operation_id: str) -> ContractCallResult:
+        """Consulta se a operação já foi aplicada no contrato (conciliação após timeout)"""
+        try:
+            args = [scval.to_bytes(self._operation_id(operation_id))]
+            result = await self._execute_contract_function("is_processed", args, self.admin_keypair, read_only=True)
+            if result.success:
+                result.result_data = {"processed": bool(result.result_data)}
+            return result
+        except Exception as e:
+            return ContractCallResult(success=False, error_message=str(e))
+
+    async def transfer_from(self, from_address: str, to_address: str, amount: int) -> ContractCallResult:
+        # `transfer` exige a assinatura do titular, que o backend não possui. A
+        # plataforma move tokens como spender: o titular precisa ter aprovado o
+        # endereço do admin (`approve`) com allowance suficiente na própria carteira.
         try:
             args = [
+                scval.to_address(self.admin_keypair.public_key),
                 scval.to_address(from_address),
                 scval.to_address(to_address),
                 scval.to_int128(amount)
             ]
-            return await self._execute_contract_function("transfer", args, signer_keypair=self.admin_keypair)
+            return await self._execute_contract_function("transfer_from", args, signer_keypair=self.admin_keypair)
         except Exception as e:
             return ContractCallResult(success=False, error_message=str(e))
 